@@ -3,10 +3,41 @@
 //! The crate `enum_traits_macros` is required for the derives.
 
 #![cfg_attr(feature = "no_std" ,no_std)]
-#![cfg_attr(feature = "nightly",feature(associated_consts))]
 
-#[cfg(not(feature = "no_std"))]use  std::{borrow};
-#[cfg(feature = "no_std")     ]use core::{borrow};
+#[cfg(not(feature = "no_std"))]use  std::{borrow,fmt};
+#[cfg(not(feature = "no_std"))]use  std::convert::TryFrom;
+#[cfg(feature = "no_std")     ]use core::{borrow,fmt};
+#[cfg(feature = "no_std")     ]use core::convert::TryFrom;
+
+pub mod map;
+pub mod set;
+
+pub use map::EnumMap;
+pub use set::EnumSet;
+
+/// The index of a variant as an `usize`
+#[inline]
+fn index_of<E>(variant: &E) -> usize where
+	E: ToIndex,
+	usize: TryFrom<<E as Index>::Type>,
+{
+	usize::try_from(variant.index()).ok().expect("the index of the variant does not fit in an usize")
+}
+
+/// The variant with the index `i` in the defined order
+#[inline]
+fn variant_from_index<E>(i: usize) -> Option<E> where
+	E: FromIndex,
+	<E as Index>::Type: TryFrom<usize>,
+{
+	<E as Index>::Type::try_from(i).ok().and_then(E::from_index)
+}
+
+/// Formats a variant name without quotes when debug formatting
+struct DebugName(&'static str);
+impl fmt::Debug for DebugName{
+	fn fmt(&self,f: &mut fmt::Formatter) -> fmt::Result{f.write_str(self.0)}
+}
 
 /// Represents the type used for indexing the variants of the enum item.
 ///`Type` should be an primitive integer type and have more values or an equal number of values compared to the number of variants in the enum item.
@@ -83,7 +114,7 @@ pub trait Index{
 /// 			3 => Enum::D,
 /// 			4 => Enum::E,
 /// 			5 => Enum::F,
-/// 			_ => ::std::mem::uninitialized(),
+/// 			_ => ::std::hint::unreachable_unchecked(),
 /// 		}
 /// 	}
 /// }
@@ -93,6 +124,10 @@ pub trait FromIndex: Index + Sized{
 	fn from_index(index: <Self as Index>::Type) -> Option<Self>;
 
 	/// Constructs `Self` from an index based on the variants' defined order
+	///
+	/// # Safety
+	/// The index must be the index of a variant, i.e. `from_index` returns `Some` for it.
+	/// Otherwise the behaviour is undefined.
 	unsafe fn from_index_unchecked(index: <Self as Index>::Type) -> Self;
 }
 
@@ -176,18 +211,68 @@ pub trait ToIndex: Index{
 /// #[derive(EnumLen)]
 /// enum Enum{A,B,C,D,E,F}
 /// ```
-#[cfg(feature = "nightly")]
+///
+/// # Example with manual impl
+///
+/// ```rust
+/// use enum_traits::*;
+///
+/// enum Enum{A,B,C,D,E,F}
+///
+/// impl Len for Enum{
+/// 	const LEN: usize = 6;
+/// }
+///
+/// let array: [u8; Enum::LEN] = [0; Enum::LEN];
+/// assert_eq!(array.len(),Enum::len());
+/// ```
 pub trait Len{
 	/// Number of variants in an enum
 	const LEN: usize;
 
+	/// Number of variants in an enum
 	#[inline(always)]
 	fn len() -> usize{<Self as Len>::LEN}
 }
-#[cfg(not(feature = "nightly"))]
-pub trait Len{
-	/// Number of variants in an enum
-	fn len() -> usize;
+
+/// An array type with one element of type `V` for every variant in an enum type.
+/// Used as the storage of `EnumMap`.
+///
+/// This trait is implemented together with `Len` when using `#[derive(EnumLen)]`
+///
+/// # Example with derive
+///
+/// ```rust,ignore
+/// #[macro_use]extern crate enum_traits_macros;
+///
+/// #[derive(EnumLen)]
+/// enum Enum{A,B,C,D,E,F}
+/// ```
+///
+/// # Example with manual impl
+///
+/// ```rust
+/// use enum_traits::*;
+///
+/// enum Enum{A,B,C,D,E,F}
+///
+/// impl Len for Enum{
+/// 	const LEN: usize = 6;
+/// }
+/// impl<V> LenArray<V> for Enum{
+/// 	type Array = [V; 6];
+///
+/// 	fn array_from_fn<F: FnMut(usize) -> V>(mut f: F) -> Self::Array{
+/// 		[f(0),f(1),f(2),f(3),f(4),f(5)]
+/// 	}
+/// }
+/// ```
+pub trait LenArray<V>: Len{
+	/// The array type. Its length should be equal to `Len::len()`.
+	type Array: AsRef<[V]> + AsMut<[V]>;
+
+	/// Constructs the array by calling `f` with every index in order
+	fn array_from_fn<F: FnMut(usize) -> V>(f: F) -> Self::Array;
 }
 
 /// Constructors for an enum type from its endpoints based on the variants' defined order
@@ -223,8 +308,110 @@ pub trait Ends: Sized{
 	fn last() -> Self;
 }
 
+/// Stepping between the variants of an enum type based on the variants' defined order.
+/// Every step is available as checked (returning `None` when stepping outside of the enum), wrapping (continuing from the other end) and saturating (stopping at the ends).
+///
+/// Derive this trait for an enum automatically using `#[derive(EnumSequential)]`
+///
+/// # Example with derive
+///
+/// ```rust,ignore
+/// #[macro_use]extern crate enum_traits_macros;
+///
+/// #[derive(EnumSequential)]
+/// enum Enum{A,B,C}
+/// ```
+///
+/// # Example with manual impl
+///
+/// ```rust
+/// use enum_traits::*;
+///
+/// #[derive(Debug,PartialEq)]
+/// enum Enum{A,B,C}
+///
+/// fn position(variant: &Enum) -> isize{
+/// 	match variant{
+/// 		&Enum::A => 0,
+/// 		&Enum::B => 1,
+/// 		&Enum::C => 2,
+/// 	}
+/// }
+/// fn from_position(position: isize) -> Option<Enum>{
+/// 	Some(match position{
+/// 		0 => Enum::A,
+/// 		1 => Enum::B,
+/// 		2 => Enum::C,
+/// 		_ => return None,
+/// 	})
+/// }
+///
+/// impl Sequential for Enum{
+/// 	fn offset(&self,n: isize) -> Option<Self>{
+/// 		position(self).checked_add(n).and_then(from_position)
+/// 	}
+/// 	fn offset_wrapping(&self,n: isize) -> Self{
+/// 		from_position((position(self) + n.rem_euclid(3)) % 3).unwrap()
+/// 	}
+/// 	fn offset_saturating(&self,n: isize) -> Self{
+/// 		from_position(position(self).saturating_add(n).max(0).min(2)).unwrap()
+/// 	}
+/// 	fn distance(from: &Self,to: &Self) -> isize{
+/// 		position(to) - position(from)
+/// 	}
+/// }
+///
+/// assert_eq!(Enum::A.succ(),Some(Enum::B));
+/// assert_eq!(Enum::A.pred(),None);
+/// assert_eq!(Enum::A.pred_wrapping(),Enum::C);
+/// assert_eq!(Enum::C.succ_saturating(),Enum::C);
+/// assert_eq!(Enum::distance(&Enum::C,&Enum::A),-2);
+/// ```
+pub trait Sequential: Sized{
+	/// The variant `n` steps after `self` in the defined order of an enum (or before if `n` is negative).
+	/// `None` if there is no such variant.
+	fn offset(&self,n: isize) -> Option<Self>;
+
+	/// The variant `n` steps after `self` in the defined order of an enum (or before if `n` is negative).
+	/// Continues from the other end when stepping past the first or the last variant.
+	fn offset_wrapping(&self,n: isize) -> Self;
+
+	/// The variant `n` steps after `self` in the defined order of an enum (or before if `n` is negative).
+	/// Stops at the first or the last variant.
+	fn offset_saturating(&self,n: isize) -> Self;
+
+	/// Number of steps from `from` to `to` in the defined order of an enum.
+	/// Negative if `to` is before `from`.
+	fn distance(from: &Self,to: &Self) -> isize;
+
+	/// The successor of `self`, or `None` if `self` is the last variant
+	#[inline]
+	fn succ(&self) -> Option<Self>{self.offset(1)}
+
+	/// The predecessor of `self`, or `None` if `self` is the first variant
+	#[inline]
+	fn pred(&self) -> Option<Self>{self.offset(-1)}
+
+	/// The successor of `self`, or the first variant if `self` is the last variant
+	#[inline]
+	fn succ_wrapping(&self) -> Self{self.offset_wrapping(1)}
+
+	/// The predecessor of `self`, or the last variant if `self` is the first variant
+	#[inline]
+	fn pred_wrapping(&self) -> Self{self.offset_wrapping(-1)}
+
+	/// The successor of `self`, or the last variant if `self` is the last variant
+	#[inline]
+	fn succ_saturating(&self) -> Self{self.offset_saturating(1)}
+
+	/// The predecessor of `self`, or the first variant if `self` is the first variant
+	#[inline]
+	fn pred_saturating(&self) -> Self{self.offset_saturating(-1)}
+}
+
 /// Derive this trait for an enum automatically using `#[derive(EnumDiscriminant)]`
-/// When this trait is derived, non-unit variants will be mapped to `None` in `from_discriminant`, and non-explicitly-specified discriminants will also be mapped to `None`.
+/// When this trait is derived, non-unit variants will be mapped to `None` in `from_discriminant`.
+/// Enums with fields are supported, and their discriminants are the ones assigned by the compiler, which are only observable when the enum has a primitive representation (e.g. `#[repr(u8)]`).
 ///
 /// # Example with derive
 ///
@@ -261,6 +448,18 @@ pub trait Ends: Sized{
 /// impl Discriminant for Enum{
 /// 	type Type = usize;
 ///
+/// 	fn discriminant(&self) -> <Self as Discriminant>::Type{
+/// 		match *self{
+/// 			Enum::A => 1,
+/// 			Enum::B => 2,
+/// 			Enum::C => 4,
+/// 			Enum::D => 8,
+/// 			Enum::E => 16,
+/// 			Enum::F => 33,
+/// 			Enum::G => 34,
+/// 		}
+/// 	}
+///
 /// 	fn from_discriminant(discriminant: <Self as Discriminant>::Type) -> Option<Self>{
 /// 		Some(match discriminant {
 /// 			 1  => Enum::A,
@@ -281,7 +480,7 @@ pub trait Ends: Sized{
 /// 			8  => Enum::D,
 /// 			16 => Enum::E,
 /// 			33 => Enum::F,
-/// 			_ => ::std::mem::uninitialized(),
+/// 			_ => ::std::hint::unreachable_unchecked(),
 /// 		}
 /// 	}
 /// }
@@ -290,16 +489,24 @@ pub trait Discriminant: Sized{
 	/// The type of the discriminant
 	type Type;
 
+	/// The discriminant of the variant
+	fn discriminant(&self) -> <Self as Discriminant>::Type;
+
 	/// Tries to construct an enum from the discriminant of the variants/enum items
 	fn from_discriminant(discriminant: <Self as Discriminant>::Type) -> Option<Self>;
 
 	/// Constructs an enum from the discriminant of the variants/enum items
+	///
+	/// # Safety
+	/// The discriminant must be the discriminant of a variant that `from_discriminant` constructs, i.e. `from_discriminant` returns `Some` for it.
+	/// Otherwise the behaviour is undefined.
 	unsafe fn from_discriminant_unchecked(discriminant: <Self as Discriminant>::Type) -> Self;
 }
 
 /// Derive this trait for an enum automatically using `#[derive(EnumIter)]`
-/// When derived, a struct named ((name of Self) + "Iter") will be created with the same visibility as `Self`.
-/// This struct will then implement `Iterator` and `Iter` will be assigned to it when implementing `Iterable` for `Self`.
+/// When derived, a struct named ((name of Self) + "Iter") will be created with the same visibility and generic parameters as `Self`.
+/// This struct will then implement `Iterator`, `DoubleEndedIterator` and `ExactSizeIterator`, and `Iter` will be assigned to it when implementing `Iterable` for `Self`.
+/// The name, visibility and derives of the struct are configurable using `#[enum_traits(iter(name = "...",vis = "...",derive(...)))]`.
 ///
 /// # Example with derive
 ///
@@ -367,6 +574,39 @@ pub trait Iterable where
 	fn variants() -> Self::Iter;
 }
 
+/// Every variant of an enum type in a slice
+///
+/// This trait is implemented together with `Iterable` when using `#[derive(EnumIter)]` on an enum where every iterated variant is an unit variant
+///
+/// # Example with derive
+///
+/// ```rust,ignore
+/// #[macro_use]extern crate enum_traits_macros;
+///
+/// #[derive(EnumIter)]
+/// enum Enum{A,B,C}
+/// ```
+///
+/// # Example with manual impl
+///
+/// ```rust
+/// use enum_traits::*;
+///
+/// #[derive(Debug,PartialEq)]
+/// enum Enum{A,B,C}
+///
+/// impl Variants for Enum{
+/// 	const VARIANTS: &'static [Self] = &[Enum::A,Enum::B,Enum::C];
+/// }
+///
+/// assert_eq!(Enum::VARIANTS,&[Enum::A,Enum::B,Enum::C]);
+/// assert_eq!(Enum::VARIANTS.iter().position(|variant| *variant == Enum::B),Some(1));
+/// ```
+pub trait Variants: Sized + 'static{
+	/// Every variant in the defined order of an enum
+	const VARIANTS: &'static [Self];
+}
+
 /// Derive this trait for an enum automatically using `#[derive(EnumVariantName)]`
 ///
 /// # Example with derive
@@ -417,6 +657,283 @@ pub trait VariantName{
 	fn variant_name(&self) -> &'static str;
 }
 
+/// The names of the variants of an enum type
+///
+/// This trait is implemented together with `VariantName` when using `#[derive(EnumVariantName)]`
+///
+/// # Example with manual impl
+///
+/// ```rust
+/// use enum_traits::*;
+///
+/// enum Enum{A,B(u8),C{c: u16}}
+///
+/// impl VariantNames for Enum{
+/// 	const NAMES: &'static [&'static str] = &["A","B","C"];
+/// }
+///
+/// assert_eq!(Enum::NAMES.join(","),"A,B,C");
+/// ```
+pub trait VariantNames{
+	/// The name of every variant in the defined order of an enum, the same as `VariantName::variant_name`
+	const NAMES: &'static [&'static str];
+}
+
+/// Derive this trait for an enum automatically using `#[derive(EnumVariantProps)]`
+/// The properties of a variant are given by `#[enum_traits(prop(key = "value",...))]` on the variant.
+///
+/// # Example with derive
+///
+/// ```rust,ignore
+/// #[macro_use]extern crate enum_traits_macros;
+///
+/// #[derive(EnumVariantProps)]
+/// enum Enum{
+/// 	#[enum_traits(prop(label = "a",icon = "circle"))]
+/// 	A,
+/// 	#[enum_traits(prop(label = "b"))]
+/// 	B(u8),
+/// 	C{c: u16},
+/// }
+/// ```
+///
+/// # Example with manual impl
+///
+/// ```rust
+/// use enum_traits::*;
+///
+/// enum Enum{
+/// 	A,
+/// 	B(u8),
+/// 	C{c: u16},
+/// }
+///
+/// impl VariantProps for Enum{
+/// 	fn props(&self) -> &'static [(&'static str,&'static str)]{
+/// 		match self{
+/// 			&Enum::A     => &[("label","a"),("icon","circle")],
+/// 			&Enum::B(..) => &[("label","b")],
+/// 			&Enum::C{..} => &[],
+/// 		}
+/// 	}
+/// }
+///
+/// assert_eq!(Enum::A.get_prop("icon"),Some("circle"));
+/// assert_eq!(Enum::B(0).get_prop("icon"),None);
+/// ```
+pub trait VariantProps{
+	/// The properties of the currently instantiated variant as key-value pairs in the order they are given
+	fn props(&self) -> &'static [(&'static str,&'static str)];
+
+	/// The value of the property with the given key of the currently instantiated variant
+	#[inline]
+	fn get_prop(&self,key: &str) -> Option<&'static str>{
+		self.props().iter().find(|&&(prop_key,_)| prop_key == key).map(|&(_,value)| value)
+	}
+}
+
+/// Derive this trait for an enum automatically using `#[derive(EnumFromVariantName)]`
+/// When derived, `FromStr` is also implemented using `ParseVariantError<'static>` as the error.
+///
+/// # Example with derive
+///
+/// ```rust,ignore
+/// #[macro_use]extern crate enum_traits_macros;
+///
+/// #[derive(EnumFromVariantName)]
+/// enum Enum{A,B,C}
+/// ```
+///
+/// # Example with manual impl
+///
+/// ```rust
+/// use enum_traits::*;
+///
+/// enum Enum{A,B,C}
+///
+/// impl FromVariantName for Enum{
+/// 	fn from_variant_name<'s>(name: &'s str) -> Result<Self,ParseVariantError<'s>>{
+/// 		Ok(match name{
+/// 			"A" => Enum::A,
+/// 			"B" => Enum::B,
+/// 			"C" => Enum::C,
+/// 			_ => return Err(ParseVariantError::new(name,"Enum")),
+/// 		})
+/// 	}
+/// }
+/// ```
+pub trait FromVariantName: Sized{
+	/// Tries to construct the variant with the given name
+	fn from_variant_name<'s>(name: &'s str) -> Result<Self,ParseVariantError<'s>>;
+}
+
+/// The error when no variant with the given name exists in an enum.
+/// Contains the rejected input and the name of the enum type.
+/// Errors are equal when their inputs and type names are equal, whether or not the input is borrowed.
+#[derive(Clone)]
+pub struct ParseVariantError<'s>{
+	#[cfg(not(feature = "no_std"))]input: borrow::Cow<'s,str>,
+	#[cfg(feature = "no_std")     ]input: Input<'s>,
+	type_name: &'static str,
+}
+
+/// The maximum length in bytes of the input kept by `ParseVariantError::into_owned` when using `no_std`
+#[cfg(feature = "no_std")]
+pub const OWNED_INPUT_CAPACITY: usize = 32;
+
+/// The rejected input when using `no_std`, either borrowed or copied into an inline buffer
+#[cfg(feature = "no_std")]
+#[derive(Clone)]
+enum Input<'s>{
+	Borrowed(&'s str),
+	/// The bytes of the input, truncated to `OWNED_INPUT_CAPACITY` at a character boundary, the number of bytes used and whether the input was truncated
+	Inline([u8; OWNED_INPUT_CAPACITY],usize,bool),
+}
+
+impl<'s> ParseVariantError<'s>{
+	/// Constructs an error for the rejected input `input` when parsing the enum type named `type_name`
+	#[inline]
+	pub fn new(input: &'s str,type_name: &'static str) -> Self{
+		#[cfg(not(feature = "no_std"))]let input = borrow::Cow::Borrowed(input);
+		#[cfg(feature = "no_std")     ]let input = Input::Borrowed(input);
+		ParseVariantError{input,type_name}
+	}
+
+	/// The rejected input.
+	/// When using `no_std`, errors that have been converted using `into_owned` only keep the first `OWNED_INPUT_CAPACITY` bytes of the input (see `is_input_truncated`).
+	#[inline]
+	pub fn input(&self) -> &str{
+		#[cfg(not(feature = "no_std"))]return &self.input;
+		#[cfg(feature = "no_std")     ]return match self.input{
+			Input::Borrowed(input) => input,
+			Input::Inline(ref bytes,len,_) => ::core::str::from_utf8(&bytes[..len]).unwrap_or(""),
+		};
+	}
+
+	/// Whether `input` is only the beginning of the rejected input.
+	/// Only when using `no_std`, for errors that have been converted using `into_owned` with an input longer than `OWNED_INPUT_CAPACITY` bytes.
+	#[inline]
+	pub fn is_input_truncated(&self) -> bool{
+		#[cfg(not(feature = "no_std"))]return false;
+		#[cfg(feature = "no_std")     ]return match self.input{
+			Input::Borrowed(_) => false,
+			Input::Inline(_,_,truncated) => truncated,
+		};
+	}
+
+	/// The name of the enum type
+	#[inline]
+	pub fn type_name(&self) -> &'static str{
+		self.type_name
+	}
+
+	/// Converts the error to one that does not borrow the input.
+	/// The input is copied. When using `no_std`, it is copied into an inline buffer because of the lack of allocation, truncating it to at most `OWNED_INPUT_CAPACITY` bytes.
+	#[inline]
+	pub fn into_owned(self) -> ParseVariantError<'static>{
+		#[cfg(not(feature = "no_std"))]let input = borrow::Cow::Owned(self.input.into_owned());
+		#[cfg(feature = "no_std")     ]let input = {
+			let input = self.input();
+			let mut len = ::core::cmp::min(input.len(),OWNED_INPUT_CAPACITY);
+			while !input.is_char_boundary(len){
+				len-= 1;
+			}
+			let mut bytes = [0; OWNED_INPUT_CAPACITY];
+			bytes[..len].copy_from_slice(&input.as_bytes()[..len]);
+			Input::Inline(bytes,len,len < input.len())
+		};
+		ParseVariantError{input,type_name: self.type_name}
+	}
+}
+
+impl<'s,'t> PartialEq<ParseVariantError<'t>> for ParseVariantError<'s>{
+	#[inline]
+	fn eq(&self,other: &ParseVariantError<'t>) -> bool{
+		self.input() == other.input() && self.is_input_truncated() == other.is_input_truncated() && self.type_name == other.type_name
+	}
+}
+
+impl<'s> Eq for ParseVariantError<'s>{}
+
+impl<'s> fmt::Debug for ParseVariantError<'s>{
+	fn fmt(&self,f: &mut fmt::Formatter) -> fmt::Result{
+		let mut debug = f.debug_struct("ParseVariantError");
+		debug.field("input",&self.input());
+		#[cfg(feature = "no_std")]debug.field("input_truncated",&self.is_input_truncated());
+		debug.field("type_name",&self.type_name).finish()
+	}
+}
+
+impl<'s> fmt::Display for ParseVariantError<'s>{
+	fn fmt(&self,f: &mut fmt::Formatter) -> fmt::Result{
+		if self.is_input_truncated(){
+			write!(f,"no variant named {:?}... in `{}`",self.input(),self.type_name)
+		}else{
+			write!(f,"no variant named {:?} in `{}`",self.input(),self.type_name)
+		}
+	}
+}
+
+#[cfg(not(feature = "no_std"))]
+impl<'s> ::std::error::Error for ParseVariantError<'s>{}
+
+/// The error when no variant has the given discriminant, returned by `TryFrom` when derived using `#[derive(EnumRepr)]`.
+/// Contains the rejected value and the name of the enum type.
+///
+/// # Example with derive
+///
+/// ```rust,ignore
+/// #[macro_use]extern crate enum_traits_macros;
+///
+/// #[derive(Debug,EnumRepr)]
+/// #[repr(u8)]
+/// enum Enum{A = 1,B = 2}
+///
+/// let error = Enum::try_from(3u8).unwrap_err();
+/// assert_eq!(error.value(),3);
+/// assert_eq!(error.to_string(),"no variant with the discriminant 3 in `Enum`");
+/// ```
+#[derive(Clone,Copy,Debug,PartialEq,Eq,Hash)]
+pub struct TryFromReprError<T>{
+	value: T,
+	type_name: &'static str,
+}
+
+impl<T> TryFromReprError<T>{
+	/// Constructs an error for the rejected value `value` when converting to the enum type named `type_name`
+	#[inline]
+	pub fn new(value: T,type_name: &'static str) -> Self{
+		TryFromReprError{value,type_name}
+	}
+
+	/// The rejected value
+	#[inline]
+	pub fn value(&self) -> T where T: Copy{
+		self.value
+	}
+
+	/// Converts the error to the rejected value
+	#[inline]
+	pub fn into_value(self) -> T{
+		self.value
+	}
+
+	/// The name of the enum type
+	#[inline]
+	pub fn type_name(&self) -> &'static str{
+		self.type_name
+	}
+}
+
+impl<T: fmt::Display> fmt::Display for TryFromReprError<T>{
+	fn fmt(&self,f: &mut fmt::Formatter) -> fmt::Result{
+		write!(f,"no variant with the discriminant {} in `{}`",self.value,self.type_name)
+	}
+}
+
+#[cfg(not(feature = "no_std"))]
+impl<T: fmt::Debug + fmt::Display> ::std::error::Error for TryFromReprError<T>{}
+
 /// Derive this trait for an enum automatically using `#[derive(EnumBitPattern)]`
 ///
 /// # Example with derive
@@ -482,7 +999,10 @@ pub trait BitPattern{
 
 /// Derive this trait for an enum automatically using `#[derive(EnumTag)]`
 /// When derived, an enum named ((name of Self) + "Tag") will be created with the same visibility as `Self`.
-/// This enum will then will be assigned to the `Iter` associated type when implementing `Tag` for `Self`.
+/// This enum will then will be assigned to the `Enum` associated type when implementing `Tag` for `Self`.
+/// The created enum has no generic parameters, so it is the same type for every instantiation of a generic `Self`.
+/// The name, visibility and derives of the enum are configurable using `#[enum_traits(tag(name = "...",vis = "...",derive(...)))]`.
+/// The enum implements `Index`, `ToIndex`, `FromIndex`, `Len`, `Iterable` and `VariantName` with the same indices and names as `Self`, and `Self` implements `PartialEq` with the enum.
 ///
 /// # Example with derive
 ///
@@ -536,4 +1056,16 @@ pub trait Tag{
 
 	/// The tag (unit variant) of the currently instantiated variant
 	fn tag(&self) -> Self::Enum;
+
+	/// Whether the currently instantiated variant has the given tag
+	#[inline]
+	fn is(&self,tag: Self::Enum) -> bool where Self::Enum: PartialEq{
+		self.tag() == tag
+	}
+
+	/// The name of the tag of the currently instantiated variant
+	#[inline]
+	fn tag_name(&self) -> &'static str where Self::Enum: VariantName{
+		self.tag().variant_name()
+	}
 }
@@ -0,0 +1,389 @@
+//! A map with one value for every variant of an enum type stored in an array.
+
+#[cfg(not(feature = "no_std"))]use  std::{fmt,hash,iter,marker,mem,ops,slice};
+#[cfg(not(feature = "no_std"))]use  std::convert::TryFrom;
+#[cfg(feature = "no_std")     ]use core::{fmt,hash,iter,marker,mem,ops,slice};
+#[cfg(feature = "no_std")     ]use core::convert::TryFrom;
+
+use {FromIndex,Index,LenArray,ToIndex,VariantName};
+use {index_of,variant_from_index,DebugName};
+
+/// A total map from the variants of the enum type `E` to values of type `V`.
+///
+/// The values are stored inline without allocation in `<E as LenArray<V>>::Array`, which contains exactly one value for every variant.
+/// The map is indexed by a variant using its index from `ToIndex`, and the variants are constructed from their indices using `FromIndex` when iterating.
+///
+/// A partial map can be represented as `EnumMap<E,Option<V>>`, which has additional methods for inserting and removing values.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[macro_use]extern crate enum_traits_macros;
+/// extern crate enum_traits;
+///
+/// use enum_traits::*;
+///
+/// #[derive(Debug,EnumIndex,EnumToIndex,EnumFromIndex,EnumLen,EnumVariantName)]
+/// enum Color{Red,Green,Blue}
+///
+/// let mut map = EnumMap::from_fn(|color| match color{
+/// 	Color::Red   => 0xFF0000,
+/// 	Color::Green => 0x00FF00,
+/// 	Color::Blue  => 0x0000FF,
+/// });
+/// assert_eq!(map[Color::Green],0x00FF00);
+///
+/// map[Color::Green] = 0x008000;
+/// assert_eq!(map[Color::Green],0x008000);
+/// ```
+pub struct EnumMap<E: LenArray<V>,V>{
+	values: <E as LenArray<V>>::Array,
+	phantom: marker::PhantomData<E>,
+}
+
+impl<E,V> EnumMap<E,V> where
+	E: ToIndex + FromIndex + LenArray<V>,
+	usize: TryFrom<<E as Index>::Type>,
+	<E as Index>::Type: TryFrom<usize>,
+{
+	/// Constructs a map by calling `f` with every variant in the defined order
+	pub fn from_fn<F>(mut f: F) -> Self
+		where F: FnMut(E) -> V
+	{
+		EnumMap{
+			values: E::array_from_fn(|i| f(variant_from_index(i).expect("the variant index should be in range"))),
+			phantom: marker::PhantomData,
+		}
+	}
+
+	/// Constructs a map from an array of values in the defined order of the variants
+	#[inline]
+	pub fn from_array(values: <E as LenArray<V>>::Array) -> Self{
+		EnumMap{values,phantom: marker::PhantomData}
+	}
+
+	/// The values of the map as an array in the defined order of the variants
+	#[inline]
+	pub fn into_array(self) -> <E as LenArray<V>>::Array{
+		self.values
+	}
+
+	/// The value of a variant
+	#[inline]
+	pub fn get(&self,key: &E) -> &V{
+		&self.values.as_ref()[index_of(key)]
+	}
+
+	/// The value of a variant
+	#[inline]
+	pub fn get_mut(&mut self,key: &E) -> &mut V{
+		&mut self.values.as_mut()[index_of(key)]
+	}
+
+	/// Replaces the value of a variant, returning the old value
+	#[inline]
+	pub fn replace(&mut self,key: &E,value: V) -> V{
+		mem::replace(self.get_mut(key),value)
+	}
+
+	/// Number of values in the map, which is the number of variants
+	#[inline]
+	pub fn len(&self) -> usize{
+		self.values.as_ref().len()
+	}
+
+	/// Whether the map is empty, which is only the case when the enum has no variants
+	#[inline]
+	pub fn is_empty(&self) -> bool{
+		self.values.as_ref().is_empty()
+	}
+
+	/// The values of the map as a slice in the defined order of the variants
+	#[inline]
+	pub fn values(&self) -> &[V]{
+		self.values.as_ref()
+	}
+
+	/// The values of the map as a slice in the defined order of the variants
+	#[inline]
+	pub fn values_mut(&mut self) -> &mut [V]{
+		self.values.as_mut()
+	}
+
+	/// Iterates over every variant and its value in the defined order
+	#[inline]
+	pub fn iter<'m>(&'m self) -> Iter<'m,E,V>{
+		Iter{inner: self.values.as_ref().iter().enumerate(),phantom: marker::PhantomData}
+	}
+
+	/// Iterates over every variant and its mutable value in the defined order
+	#[inline]
+	pub fn iter_mut<'m>(&'m mut self) -> IterMut<'m,E,V>{
+		IterMut{inner: self.values.as_mut().iter_mut().enumerate(),phantom: marker::PhantomData}
+	}
+}
+
+/// Methods for partial maps, where a missing value is represented by `None`
+impl<E,V> EnumMap<E,Option<V>> where
+	E: ToIndex + FromIndex + LenArray<Option<V>>,
+	usize: TryFrom<<E as Index>::Type>,
+	<E as Index>::Type: TryFrom<usize>,
+{
+	/// Constructs a map with no values present
+	#[inline]
+	pub fn empty() -> Self{
+		EnumMap{values: E::array_from_fn(|_| None),phantom: marker::PhantomData}
+	}
+
+	/// Inserts a value for a variant, returning the old value if it was present
+	#[inline]
+	pub fn insert(&mut self,key: &E,value: V) -> Option<V>{
+		self.replace(key,Some(value))
+	}
+
+	/// Removes the value of a variant, returning it if it was present
+	#[inline]
+	pub fn remove(&mut self,key: &E) -> Option<V>{
+		self.get_mut(key).take()
+	}
+
+	/// Whether a value is present for the variant
+	#[inline]
+	pub fn contains_key(&self,key: &E) -> bool{
+		self.get(key).is_some()
+	}
+
+	/// Iterates over every variant that has a value present and its value in the defined order
+	#[inline]
+	pub fn present<'m>(&'m self) -> Present<'m,E,V>{
+		Present{inner: self.iter()}
+	}
+
+	/// Constructs a total map if every variant has a value present, otherwise the partial map is returned
+	pub fn into_total(mut self) -> Result<EnumMap<E,V>,Self> where
+		E: LenArray<V>
+	{
+		if self.values.as_ref().iter().all(Option::is_some){
+			let mut values = self.values.as_mut().iter_mut();
+			Ok(EnumMap{
+				values: <E as LenArray<V>>::array_from_fn(|_| values.next().and_then(Option::take).expect("every value should be present")),
+				phantom: marker::PhantomData,
+			})
+		}else{
+			Err(self)
+		}
+	}
+}
+
+impl<E,V> ops::Index<E> for EnumMap<E,V> where
+	E: ToIndex + FromIndex + LenArray<V>,
+	usize: TryFrom<<E as Index>::Type>,
+	<E as Index>::Type: TryFrom<usize>,
+{
+	type Output = V;
+
+	#[inline]
+	fn index(&self,key: E) -> &V{
+		self.get(&key)
+	}
+}
+
+impl<E,V> ops::IndexMut<E> for EnumMap<E,V> where
+	E: ToIndex + FromIndex + LenArray<V>,
+	usize: TryFrom<<E as Index>::Type>,
+	<E as Index>::Type: TryFrom<usize>,
+{
+	#[inline]
+	fn index_mut(&mut self,key: E) -> &mut V{
+		self.get_mut(&key)
+	}
+}
+
+/// Iterator over the variants and values of an `EnumMap` in the defined order of the enum
+pub struct Iter<'m,E,V: 'm>{
+	inner: iter::Enumerate<slice::Iter<'m,V>>,
+	phantom: marker::PhantomData<E>,
+}
+
+impl<'m,E,V> Iterator for Iter<'m,E,V> where
+	E: FromIndex,
+	<E as Index>::Type: TryFrom<usize>,
+{
+	type Item = (E,&'m V);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item>{
+		self.inner.next().and_then(|(i,value)| variant_from_index(i).map(|variant| (variant,value)))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize,Option<usize>){
+		self.inner.size_hint()
+	}
+}
+
+impl<'m,E,V> DoubleEndedIterator for Iter<'m,E,V> where
+	E: FromIndex,
+	<E as Index>::Type: TryFrom<usize>,
+{
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item>{
+		self.inner.next_back().and_then(|(i,value)| variant_from_index(i).map(|variant| (variant,value)))
+	}
+}
+
+impl<'m,E,V> ExactSizeIterator for Iter<'m,E,V> where
+	E: FromIndex,
+	<E as Index>::Type: TryFrom<usize>,
+{}
+
+/// Iterator over the variants that has a value present and their values of a partial `EnumMap` in the defined order of the enum
+pub struct Present<'m,E,V: 'm>{
+	inner: Iter<'m,E,Option<V>>,
+}
+
+impl<'m,E,V> Iterator for Present<'m,E,V> where
+	E: FromIndex,
+	<E as Index>::Type: TryFrom<usize>,
+{
+	type Item = (E,&'m V);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item>{
+		for (variant,value) in &mut self.inner{
+			if let Some(ref value) = *value{
+				return Some((variant,value));
+			}
+		}
+		None
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize,Option<usize>){
+		(0,self.inner.size_hint().1)
+	}
+}
+
+/// Iterator over the variants and mutable values of an `EnumMap` in the defined order of the enum
+pub struct IterMut<'m,E,V: 'm>{
+	inner: iter::Enumerate<slice::IterMut<'m,V>>,
+	phantom: marker::PhantomData<E>,
+}
+
+impl<'m,E,V> Iterator for IterMut<'m,E,V> where
+	E: FromIndex,
+	<E as Index>::Type: TryFrom<usize>,
+{
+	type Item = (E,&'m mut V);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item>{
+		self.inner.next().and_then(|(i,value)| variant_from_index(i).map(|variant| (variant,value)))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize,Option<usize>){
+		self.inner.size_hint()
+	}
+}
+
+impl<'m,E,V> DoubleEndedIterator for IterMut<'m,E,V> where
+	E: FromIndex,
+	<E as Index>::Type: TryFrom<usize>,
+{
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item>{
+		self.inner.next_back().and_then(|(i,value)| variant_from_index(i).map(|variant| (variant,value)))
+	}
+}
+
+impl<'m,E,V> ExactSizeIterator for IterMut<'m,E,V> where
+	E: FromIndex,
+	<E as Index>::Type: TryFrom<usize>,
+{}
+
+impl<'m,E,V> IntoIterator for &'m EnumMap<E,V> where
+	E: ToIndex + FromIndex + LenArray<V>,
+	usize: TryFrom<<E as Index>::Type>,
+	<E as Index>::Type: TryFrom<usize>,
+{
+	type Item = (E,&'m V);
+	type IntoIter = Iter<'m,E,V>;
+
+	#[inline]
+	fn into_iter(self) -> Iter<'m,E,V>{self.iter()}
+}
+
+impl<'m,E,V> IntoIterator for &'m mut EnumMap<E,V> where
+	E: ToIndex + FromIndex + LenArray<V>,
+	usize: TryFrom<<E as Index>::Type>,
+	<E as Index>::Type: TryFrom<usize>,
+{
+	type Item = (E,&'m mut V);
+	type IntoIter = IterMut<'m,E,V>;
+
+	#[inline]
+	fn into_iter(self) -> IterMut<'m,E,V>{self.iter_mut()}
+}
+
+impl<E,V> Default for EnumMap<E,V> where
+	E: LenArray<V>,
+	V: Default,
+{
+	#[inline]
+	fn default() -> Self{
+		EnumMap{values: E::array_from_fn(|_| V::default()),phantom: marker::PhantomData}
+	}
+}
+
+impl<E,V> Clone for EnumMap<E,V> where
+	E: LenArray<V>,
+	<E as LenArray<V>>::Array: Clone,
+{
+	#[inline]
+	fn clone(&self) -> Self{
+		EnumMap{values: self.values.clone(),phantom: marker::PhantomData}
+	}
+}
+
+impl<E,V> Copy for EnumMap<E,V> where
+	E: LenArray<V>,
+	<E as LenArray<V>>::Array: Copy,
+{}
+
+impl<E,V> PartialEq for EnumMap<E,V> where
+	E: LenArray<V>,
+	V: PartialEq,
+{
+	#[inline]
+	fn eq(&self,other: &Self) -> bool{
+		self.values.as_ref() == other.values.as_ref()
+	}
+}
+
+impl<E,V> Eq for EnumMap<E,V> where
+	E: LenArray<V>,
+	V: Eq,
+{}
+
+impl<E,V> hash::Hash for EnumMap<E,V> where
+	E: LenArray<V>,
+	V: hash::Hash,
+{
+	#[inline]
+	fn hash<H: hash::Hasher>(&self,state: &mut H){
+		self.values.as_ref().hash(state)
+	}
+}
+
+/// Formats the map using the variant names from `VariantName`
+impl<E,V> fmt::Debug for EnumMap<E,V> where
+	E: ToIndex + FromIndex + LenArray<V> + VariantName,
+	V: fmt::Debug,
+	usize: TryFrom<<E as Index>::Type>,
+	<E as Index>::Type: TryFrom<usize>,
+{
+	fn fmt(&self,f: &mut fmt::Formatter) -> fmt::Result{
+		f.debug_map().entries(self.iter().map(|(variant,value)| (DebugName(variant.variant_name()),value))).finish()
+	}
+}
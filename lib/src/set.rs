@@ -0,0 +1,326 @@
+//! A set of variants of an enum type stored as a bit set.
+
+#[cfg(not(feature = "no_std"))]use  std::{cmp,fmt,hash,iter,marker};
+#[cfg(not(feature = "no_std"))]use  std::convert::TryFrom;
+#[cfg(feature = "no_std")     ]use core::{cmp,fmt,hash,iter,marker};
+#[cfg(feature = "no_std")     ]use core::convert::TryFrom;
+
+use {BitPattern,FromIndex,Index,Len,ToIndex,VariantName};
+use {index_of,variant_from_index,DebugName};
+
+/// A set of variants of the enum type `E`.
+///
+/// The set is stored inline without allocation in `<E as BitPattern>::ByteArray`, using the same bit layout as `BitPattern::bit_pattern`.
+/// This means that a set containing a single variant has the same bytes as the bit pattern of that variant.
+/// Membership is determined by the variant's index from `ToIndex`, and the variants are constructed from their indices using `FromIndex` when iterating.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[macro_use]extern crate enum_traits_macros;
+/// extern crate enum_traits;
+///
+/// use enum_traits::*;
+///
+/// #[derive(Debug,EnumIndex,EnumToIndex,EnumFromIndex,EnumLen,EnumBitPattern,EnumVariantName)]
+/// enum Permission{Read,Write,Execute}
+///
+/// let mut set = EnumSet::new();
+/// set.insert(Permission::Read);
+/// set.insert(Permission::Execute);
+/// assert!(set.contains(&Permission::Read));
+/// assert!(!set.contains(&Permission::Write));
+/// assert_eq!(set.len(),2);
+/// assert_eq!(format!("{:?}",set),"{Read, Execute}");
+/// ```
+pub struct EnumSet<E: BitPattern>{
+	bits: <E as BitPattern>::ByteArray,
+	phantom: marker::PhantomData<E>,
+}
+
+impl<E> EnumSet<E> where
+	E: ToIndex + FromIndex + Len + BitPattern,
+	<E as BitPattern>::ByteArray: AsRef<[u8]> + AsMut<[u8]> + Default,
+	usize: TryFrom<<E as Index>::Type>,
+	<E as Index>::Type: TryFrom<usize>,
+{
+	/// Constructs an empty set
+	#[inline]
+	pub fn new() -> Self{
+		EnumSet{bits: Default::default(),phantom: marker::PhantomData}
+	}
+
+	/// Constructs a set containing every variant of `E`
+	pub fn all() -> Self{
+		let mut set = Self::new();
+		for i in 0..E::len(){
+			set.set_bit(i,true);
+		}
+		set
+	}
+
+	/// Constructs a set from a bit pattern with the same layout as `BitPattern::bit_pattern`.
+	/// Bits not corresponding to any variant are ignored.
+	pub fn from_bit_pattern(bits: <E as BitPattern>::ByteArray) -> Self{
+		let mut set = EnumSet{bits,phantom: marker::PhantomData};
+		let len = E::len();
+		for i in len..set.capacity(){
+			set.set_bit(i,false);
+		}
+		set
+	}
+
+	/// The bit pattern of the set with the same layout as `BitPattern::bit_pattern`
+	#[inline]
+	pub fn bit_pattern(&self) -> &<E as BitPattern>::ByteArray{
+		&self.bits
+	}
+
+	/// Inserts a variant into the set.
+	/// Returns whether the variant was not present in the set before.
+	pub fn insert(&mut self,variant: E) -> bool{
+		let i = index_of(&variant);
+		let absent = !self.get_bit(i);
+		self.set_bit(i,true);
+		absent
+	}
+
+	/// Removes a variant from the set.
+	/// Returns whether the variant was present in the set before.
+	pub fn remove(&mut self,variant: &E) -> bool{
+		let i = index_of(variant);
+		let present = self.get_bit(i);
+		self.set_bit(i,false);
+		present
+	}
+
+	/// Whether the variant is present in the set
+	#[inline]
+	pub fn contains(&self,variant: &E) -> bool{
+		self.get_bit(index_of(variant))
+	}
+
+	/// Number of variants in the set
+	pub fn len(&self) -> usize{
+		self.bits.as_ref().iter().map(|byte| byte.count_ones() as usize).sum()
+	}
+
+	/// Whether the set contains no variants
+	pub fn is_empty(&self) -> bool{
+		self.bits.as_ref().iter().all(|&byte| byte == 0)
+	}
+
+	/// Removes every variant from the set
+	pub fn clear(&mut self){
+		for byte in self.bits.as_mut().iter_mut(){
+			*byte = 0;
+		}
+	}
+
+	/// The set of variants present in `self` or in `other`
+	pub fn union(&self,other: &Self) -> Self{
+		self.zip_with(other,|a,b| a | b)
+	}
+
+	/// The set of variants present in both `self` and `other`
+	pub fn intersection(&self,other: &Self) -> Self{
+		self.zip_with(other,|a,b| a & b)
+	}
+
+	/// The set of variants present in `self` but not in `other`
+	pub fn difference(&self,other: &Self) -> Self{
+		self.zip_with(other,|a,b| a & !b)
+	}
+
+	/// The set of variants present in either `self` or `other`, but not in both
+	pub fn symmetric_difference(&self,other: &Self) -> Self{
+		self.zip_with(other,|a,b| a ^ b)
+	}
+
+	/// The set of variants not present in `self`
+	pub fn complement(&self) -> Self{
+		Self::all().difference(self)
+	}
+
+	/// Whether every variant in `self` is also present in `other`
+	pub fn is_subset(&self,other: &Self) -> bool{
+		self.difference(other).is_empty()
+	}
+
+	/// Whether every variant in `other` is also present in `self`
+	#[inline]
+	pub fn is_superset(&self,other: &Self) -> bool{
+		other.is_subset(self)
+	}
+
+	/// Whether `self` and `other` have no variants in common
+	pub fn is_disjoint(&self,other: &Self) -> bool{
+		self.intersection(other).is_empty()
+	}
+
+	/// Iterates over the variants in the set in the defined order of the enum
+	#[inline]
+	pub fn iter<'s>(&'s self) -> Iter<'s,E>{
+		Iter{set: self,index: 0}
+	}
+
+	#[inline]
+	fn capacity(&self) -> usize{
+		self.bits.as_ref().len() * 8
+	}
+
+	#[inline]
+	fn get_bit(&self,i: usize) -> bool{
+		let bytes = self.bits.as_ref();
+		bytes[bytes.len()-i/8-1] & (1 << (i%8)) != 0
+	}
+
+	#[inline]
+	fn set_bit(&mut self,i: usize,value: bool){
+		let bytes = self.bits.as_mut();
+		let byte = &mut bytes[bytes.len()-i/8-1];
+		if value{
+			*byte |= 1 << (i%8);
+		}else{
+			*byte &= !(1 << (i%8));
+		}
+	}
+
+	fn zip_with<F>(&self,other: &Self,f: F) -> Self
+		where F: Fn(u8,u8) -> u8
+	{
+		let mut set = Self::new();
+		for ((out,&a),&b) in set.bits.as_mut().iter_mut().zip(self.bits.as_ref().iter()).zip(other.bits.as_ref().iter()){
+			*out = f(a,b);
+		}
+		set
+	}
+}
+
+/// Iterator over the variants of an `EnumSet` in the defined order of the enum
+pub struct Iter<'s,E: BitPattern + 's>{
+	set: &'s EnumSet<E>,
+	index: usize,
+}
+
+impl<'s,E> Iterator for Iter<'s,E> where
+	E: ToIndex + FromIndex + Len + BitPattern,
+	<E as BitPattern>::ByteArray: AsRef<[u8]> + AsMut<[u8]> + Default,
+	usize: TryFrom<<E as Index>::Type>,
+	<E as Index>::Type: TryFrom<usize>,
+{
+	type Item = E;
+
+	fn next(&mut self) -> Option<E>{
+		let len = cmp::min(E::len(),self.set.capacity());
+		while self.index < len{
+			let i = self.index;
+			self.index+= 1;
+			if self.set.get_bit(i){
+				return variant_from_index(i);
+			}
+		}
+		None
+	}
+}
+
+impl<'s,E> IntoIterator for &'s EnumSet<E> where
+	E: ToIndex + FromIndex + Len + BitPattern,
+	<E as BitPattern>::ByteArray: AsRef<[u8]> + AsMut<[u8]> + Default,
+	usize: TryFrom<<E as Index>::Type>,
+	<E as Index>::Type: TryFrom<usize>,
+{
+	type Item = E;
+	type IntoIter = Iter<'s,E>;
+
+	#[inline]
+	fn into_iter(self) -> Iter<'s,E>{self.iter()}
+}
+
+impl<E> iter::FromIterator<E> for EnumSet<E> where
+	E: ToIndex + FromIndex + Len + BitPattern,
+	<E as BitPattern>::ByteArray: AsRef<[u8]> + AsMut<[u8]> + Default,
+	usize: TryFrom<<E as Index>::Type>,
+	<E as Index>::Type: TryFrom<usize>,
+{
+	fn from_iter<I: IntoIterator<Item = E>>(iter: I) -> Self{
+		let mut set = Self::new();
+		set.extend(iter);
+		set
+	}
+}
+
+impl<E> Extend<E> for EnumSet<E> where
+	E: ToIndex + FromIndex + Len + BitPattern,
+	<E as BitPattern>::ByteArray: AsRef<[u8]> + AsMut<[u8]> + Default,
+	usize: TryFrom<<E as Index>::Type>,
+	<E as Index>::Type: TryFrom<usize>,
+{
+	fn extend<I: IntoIterator<Item = E>>(&mut self,iter: I){
+		for variant in iter{
+			self.insert(variant);
+		}
+	}
+}
+
+impl<E> Default for EnumSet<E> where
+	E: BitPattern,
+	<E as BitPattern>::ByteArray: Default,
+{
+	#[inline]
+	fn default() -> Self{
+		EnumSet{bits: Default::default(),phantom: marker::PhantomData}
+	}
+}
+
+impl<E> Clone for EnumSet<E> where
+	E: BitPattern,
+	<E as BitPattern>::ByteArray: Clone,
+{
+	#[inline]
+	fn clone(&self) -> Self{
+		EnumSet{bits: self.bits.clone(),phantom: marker::PhantomData}
+	}
+}
+
+impl<E> Copy for EnumSet<E> where
+	E: BitPattern,
+	<E as BitPattern>::ByteArray: Copy,
+{}
+
+impl<E> PartialEq for EnumSet<E> where
+	E: BitPattern,
+	<E as BitPattern>::ByteArray: PartialEq,
+{
+	#[inline]
+	fn eq(&self,other: &Self) -> bool{
+		self.bits == other.bits
+	}
+}
+
+impl<E> Eq for EnumSet<E> where
+	E: BitPattern,
+	<E as BitPattern>::ByteArray: Eq,
+{}
+
+impl<E> hash::Hash for EnumSet<E> where
+	E: BitPattern,
+	<E as BitPattern>::ByteArray: hash::Hash,
+{
+	#[inline]
+	fn hash<H: hash::Hasher>(&self,state: &mut H){
+		self.bits.hash(state)
+	}
+}
+
+/// Formats the set using the variant names from `VariantName`
+impl<E> fmt::Debug for EnumSet<E> where
+	E: ToIndex + FromIndex + Len + BitPattern + VariantName,
+	<E as BitPattern>::ByteArray: AsRef<[u8]> + AsMut<[u8]> + Default,
+	usize: TryFrom<<E as Index>::Type>,
+	<E as Index>::Type: TryFrom<usize>,
+{
+	fn fmt(&self,f: &mut fmt::Formatter) -> fmt::Result{
+		f.debug_set().entries(self.iter().map(|variant| DebugName(variant.variant_name()))).finish()
+	}
+}
@@ -0,0 +1,362 @@
+//! Parsing of the `#[enum_traits(...)]` attributes on enum items and variants.
+//! Every derive parses the same set of options, so an option meant for one derive is accepted by the others.
+
+#[cfg(feature = "no_std_compile")]use alloc::string::{String,ToString};
+#[cfg(feature = "no_std_compile")]use alloc::vec::Vec;
+use syn::{Attribute,Error,Expr,Ident,LitStr,Path,Type,Variant,Visibility};
+use syn::ext::IdentExt;
+use syn::meta::ParseNestedMeta;
+use syn::parse::{Parse,ParseStream};
+use case::RenameRule;
+
+const ATTR_NAME: &str = "enum_traits";
+
+/// The options of variants, which are not usable as names of associated values
+const VARIANT_OPTIONS: &[&str] = &["alias","construct","constructor","display","is_fn","prop","rename","skip","variant_struct"];
+
+/// Options given to an enum item
+#[derive(Default)]
+pub struct EnumAttrs{
+	/// `#[enum_traits(parse(case_insensitive))]`: Whether variant names are matched case insensitively when parsing
+	pub parse_case_insensitive: bool,
+	/// `#[enum_traits(rename_all = "...")]`: The case convention of the variant names
+	pub rename_all: Option<RenameRule>,
+	/// `#[enum_traits(default_fields)]`: Whether variants with fields are constructed using `Default` for every field
+	pub default_fields: bool,
+	/// `#[enum_traits(assoc(name: Type,...))]`: The associated values that every variant has
+	pub assoc: Vec<AssocField>,
+	/// `#[enum_traits(tag(...))]`: The enum created by `derive(EnumTag)`
+	pub tag: TypeAttrs,
+	/// `#[enum_traits(tag(iter(...)))]`: The struct created by `derive(EnumIter)` for the enum created by `derive(EnumTag)`
+	pub tag_iter: TypeAttrs,
+	/// `#[enum_traits(iter(...))]`: The struct created by `derive(EnumIter)`
+	pub iter: TypeAttrs,
+	/// `#[enum_traits(is_fn(...))]`: The functions created by `derive(EnumIsVariantFns)`
+	pub is_fn: FnAttrs,
+	/// `#[enum_traits(constructor(...))]`: The functions created by `derive(EnumConstructors)`
+	pub constructor: FnAttrs,
+	/// `#[enum_traits(variant_struct(...))]`: The structs created by `derive(EnumVariantStructs)`. Has no name.
+	pub variant_struct: TypeAttrs,
+}
+
+/// Options of a type created by a derive
+#[derive(Default)]
+pub struct TypeAttrs{
+	/// `name = "..."`: The name of the type, overriding the default name
+	pub name: Option<Ident>,
+	/// `vis = "..."`: The visibility of the type, overriding the visibility of the enum
+	pub vis: Option<Visibility>,
+	/// `derive(...)`: Additional derives of the type
+	pub derives: Vec<Path>,
+}
+
+impl TypeAttrs{
+	/// The name of the type, or the name of the enum followed by `suffix`
+	pub fn name(&self,ident: &Ident,suffix: &str) -> Ident{
+		match self.name{
+			Some(ref name) => name.clone(),
+			None => format_ident!("{}{}",ident,suffix),
+		}
+	}
+
+	/// The visibility of the type, or the visibility of the enum
+	pub fn vis<'a>(&'a self,enum_vis: &'a Visibility) -> &'a Visibility{
+		self.vis.as_ref().unwrap_or(enum_vis)
+	}
+
+	/// Parses the options of the type. `iter` is given the options of the nested `iter(...)`, if accepted.
+	fn parse(&mut self,meta: &ParseNestedMeta,mut iter: Option<&mut TypeAttrs>) -> syn::Result<()>{
+		let type_option = item_name(meta);
+		meta.parse_nested_meta(|option|{
+			if option.path.is_ident("name"){
+				let value = str_value(&option)?;
+				self.name = Some(value.parse()?);
+				Ok(())
+			}else if option.path.is_ident("vis"){
+				let value = str_value(&option)?;
+				self.vis = Some(value.parse()?);
+				Ok(())
+			}else if option.path.is_ident("derive"){
+				option.parse_nested_meta(|derive|{
+					self.derives.push(derive.path);
+					Ok(())
+				})
+			}else{
+				match iter{
+					Some(ref mut iter) if option.path.is_ident("iter") => iter.parse(&option,None),
+					_ => Err(option.error(format!("unknown option `{}` in `#[{}({}(...))]`",item_name(&option),ATTR_NAME,type_option))),
+				}
+			}
+		})
+	}
+}
+
+/// An associated value declared by `#[enum_traits(assoc(name: Type))]` or `#[enum_traits(assoc(unique name: Type))]`
+pub struct AssocField{
+	pub name: Ident,
+	pub ty: Type,
+	/// Whether no two variants have the same value, making the variant constructible from the value
+	pub unique: bool,
+}
+
+impl Parse for AssocField{
+	fn parse(input: ParseStream) -> syn::Result<Self>{
+		let mut name: Ident = input.parse()?;
+		let unique = name == "unique" && input.peek(Ident);
+		if unique{
+			name = input.parse()?;
+		}
+		input.parse::<Token![:]>()?;
+		Ok(AssocField{name,unique,ty: input.parse()?})
+	}
+}
+
+/// Options given to a variant
+#[derive(Default)]
+pub struct VariantAttrs{
+	/// `#[enum_traits(alias = "...")]`: Additional names accepted when parsing
+	pub aliases: Vec<String>,
+	/// `#[enum_traits(rename = "...")]`: The name of the variant, overriding `rename_all`
+	pub rename: Option<String>,
+	/// `#[enum_traits(skip(iter))]`: Whether the variant is excluded from iteration, the number of variants, the ends and the indices
+	pub skip_iter: bool,
+	/// `#[enum_traits(skip(parse))]`: Whether the variant is excluded from parsing
+	pub skip_parse: bool,
+	/// `#[enum_traits(construct = "...")]`: An expression constructing the variant, overriding `default_fields`
+	pub construct: Option<Expr>,
+	/// `#[enum_traits(display = "...")]`: The format string used by `Display`, referring to the fields by `{0}` or `{name}`
+	pub display: Option<LitStr>,
+	/// `#[enum_traits(prop(key = "value",...))]`: The properties of the variant in the order they are given
+	pub props: Vec<(String,String)>,
+	/// `#[enum_traits(name = value)]`: The associated values of the variant, named by `#[enum_traits(assoc(...))]` on the enum
+	pub assoc_values: Vec<(Ident,Expr)>,
+	/// `#[enum_traits(is_fn(...))]`: The function created by `derive(EnumIsVariantFns)`, overriding the options of the enum
+	pub is_fn: FnAttrs,
+	/// `#[enum_traits(constructor(...))]`: The function created by `derive(EnumConstructors)`, overriding the options of the enum
+	pub constructor: FnAttrs,
+	/// `#[enum_traits(variant_struct(...))]`: The struct created by `derive(EnumVariantStructs)`, overriding the visibility of the enum and adding to its derives
+	pub variant_struct: TypeAttrs,
+}
+
+impl VariantAttrs{
+	/// The name of the variant used by `VariantName`, `FromVariantName` and the `is_*` functions
+	pub fn name(&self,enum_attrs: &EnumAttrs,ident: &Ident) -> String{
+		match (self.rename.as_ref(),enum_attrs.rename_all){
+			(Some(name),_)    => name.clone(),
+			(None,Some(rule)) => rule.apply(&ident.to_string()),
+			(None,None)       => ident.to_string(),
+		}
+	}
+}
+
+/// Calls `parse_item` for every item inside of every `#[enum_traits(...)]` attribute
+fn parse_items<F>(attrs: &[Attribute],mut parse_item: F) -> syn::Result<()>
+	where F: FnMut(ParseNestedMeta) -> syn::Result<()>
+{
+	for attr in attrs.iter().filter(|attr| attr.path().is_ident(ATTR_NAME)){
+		attr.parse_nested_meta(&mut parse_item)?;
+	}
+	Ok(())
+}
+
+/// Options of the functions created for every variant by a derive
+#[derive(Default)]
+pub struct FnAttrs{
+	/// `prefix = "..."`: The prefix of the name of the function
+	pub prefix: Option<String>,
+	/// `name = "..."`: The name of the function following the prefix, instead of the name of the variant. Only given on variants.
+	pub name: Option<String>,
+	/// `into`: Whether the parameters are `impl Into<...>` of the types of the fields. Only accepted by `constructor(...)`.
+	pub into: bool,
+}
+
+impl FnAttrs{
+	/// The prefix of the function of a variant, from the options of the variant, the enum or `default`
+	pub fn prefix<'a>(&'a self,enum_fn_attrs: &'a FnAttrs,default: &'a str) -> &'a str{
+		self.prefix.as_ref().or(enum_fn_attrs.prefix.as_ref()).map(String::as_str).unwrap_or(default)
+	}
+
+	fn parse(&mut self,meta: &ParseNestedMeta,on_variant: bool) -> syn::Result<()>{
+		let fn_option = item_name(meta);
+		meta.parse_nested_meta(|option|{
+			if option.path.is_ident("prefix"){
+				self.prefix = Some(str_value(&option)?.value());
+				Ok(())
+			}else if on_variant && option.path.is_ident("name"){
+				self.name = Some(str_value(&option)?.value());
+				Ok(())
+			}else if fn_option == "constructor" && option.path.is_ident("into"){
+				self.into = true;
+				Ok(())
+			}else{
+				Err(option.error(format!("unknown option `{}` in `#[{}({}(...))]` on {}",item_name(&option),ATTR_NAME,fn_option,if on_variant{"a variant"}else{"an enum"})))
+			}
+		})
+	}
+}
+
+fn item_name(meta: &ParseNestedMeta) -> String{
+	meta.path.get_ident().map(Ident::to_string).unwrap_or_else(|| "path".to_string())
+}
+
+fn str_value(meta: &ParseNestedMeta) -> syn::Result<LitStr>{
+	let name = item_name(meta);
+	meta.value()
+		.and_then(|value| value.parse::<LitStr>())
+		.map_err(|_| meta.error(format!("expected `#[{}({} = \"...\")]`",ATTR_NAME,name)))
+}
+
+pub fn enum_attrs(attrs: &[Attribute]) -> syn::Result<EnumAttrs>{
+	let mut out = EnumAttrs::default();
+	parse_items(attrs,|meta|{
+		if meta.path.is_ident("parse"){
+			meta.parse_nested_meta(|option|{
+				if option.path.is_ident("case_insensitive"){
+					out.parse_case_insensitive = true;
+					Ok(())
+				}else{
+					Err(option.error(format!("unknown option `{}` in `#[{}(parse(...))]`",item_name(&option),ATTR_NAME)))
+				}
+			})
+		}else if meta.path.is_ident("assoc"){
+			let content;
+			parenthesized!(content in meta.input);
+			for field in content.parse_terminated(AssocField::parse,Token![,])?{
+				if VARIANT_OPTIONS.iter().any(|&option| field.name == option){
+					return Err(Error::new_spanned(&field.name,format!("`{}` is an option of `#[{}(...)]` and cannot be the name of an associated value",field.name,ATTR_NAME)));
+				}
+				if out.assoc.iter().any(|other| other.name == field.name){
+					return Err(Error::new_spanned(&field.name,format!("the associated value `{}` is declared more than once",field.name)));
+				}
+				out.assoc.push(field);
+			}
+			Ok(())
+		}else if meta.path.is_ident("is_fn"){
+			out.is_fn.parse(&meta,false)
+		}else if meta.path.is_ident("constructor"){
+			out.constructor.parse(&meta,false)
+		}else if meta.path.is_ident("tag"){
+			out.tag.parse(&meta,Some(&mut out.tag_iter))
+		}else if meta.path.is_ident("iter"){
+			out.iter.parse(&meta,None)
+		}else if meta.path.is_ident("variant_struct"){
+			out.variant_struct.parse(&meta,None)?;
+			match out.variant_struct.name{
+				Some(ref name) => Err(Error::new_spanned(name,format!("`#[{}(variant_struct(name = \"...\"))]` is only accepted on variants",ATTR_NAME))),
+				None => Ok(()),
+			}
+		}else if meta.path.is_ident("default_fields"){
+			out.default_fields = true;
+			Ok(())
+		}else if meta.path.is_ident("rename_all"){
+			let value = str_value(&meta)?;
+			out.rename_all = Some(RenameRule::from_name(&value.value()).ok_or_else(||{
+				let names: Vec<&str> = RenameRule::NAMES.iter().map(|&(name,_)| name).collect();
+				Error::new_spanned(&value,format!("unknown case convention \"{}\" in `#[{}(rename_all = \"...\")]`, expected one of: {}",value.value(),ATTR_NAME,names.join(", ")))
+			})?);
+			Ok(())
+		}else{
+			Err(meta.error(format!("unknown option `{}` in `#[{}(...)]` on an enum",item_name(&meta),ATTR_NAME)))
+		}
+	})?;
+	Ok(out)
+}
+
+pub fn variant_attrs(attrs: &[Attribute]) -> syn::Result<VariantAttrs>{
+	let mut out = VariantAttrs::default();
+	parse_items(attrs,|meta|{
+		if meta.path.is_ident("skip"){
+			//`skip` is the same as `skip(iter,parse)`
+			if !meta.input.peek(syn::token::Paren){
+				out.skip_iter  = true;
+				out.skip_parse = true;
+				return Ok(());
+			}
+			meta.parse_nested_meta(|option|{
+				if option.path.is_ident("iter"){
+					out.skip_iter = true;
+				}else if option.path.is_ident("parse"){
+					out.skip_parse = true;
+				}else{
+					return Err(option.error(format!("unknown option `{}` in `#[{}(skip(...))]`",item_name(&option),ATTR_NAME)));
+				}
+				Ok(())
+			})
+		}else if meta.path.is_ident("alias"){
+			out.aliases.push(str_value(&meta)?.value());
+			Ok(())
+		}else if meta.path.is_ident("construct"){
+			let value = str_value(&meta)?;
+			if out.construct.replace(value.parse()?).is_some(){
+				return Err(Error::new_spanned(&value,format!("`#[{}(construct = \"...\")]` is given more than once on a variant",ATTR_NAME)));
+			}
+			Ok(())
+		}else if meta.path.is_ident("is_fn"){
+			out.is_fn.parse(&meta,true)
+		}else if meta.path.is_ident("constructor"){
+			out.constructor.parse(&meta,true)
+		}else if meta.path.is_ident("variant_struct"){
+			out.variant_struct.parse(&meta,None)
+		}else if meta.path.is_ident("display"){
+			let value = str_value(&meta)?;
+			if out.display.replace(value.clone()).is_some(){
+				return Err(Error::new_spanned(&value,format!("`#[{}(display = \"...\")]` is given more than once on a variant",ATTR_NAME)));
+			}
+			Ok(())
+		}else if meta.path.is_ident("prop"){
+			meta.parse_nested_meta(|prop|{
+				let key = match prop.path.get_ident(){
+					Some(key) => key.unraw().to_string(),
+					None => return Err(prop.error(format!("expected `#[{}(prop(key = \"value\"))]`",ATTR_NAME))),
+				};
+				let value = str_value(&prop)?;
+				if out.props.iter().any(|(other_key,_)| *other_key == key){
+					return Err(Error::new_spanned(&prop.path,format!("the property `{}` is given more than once on a variant",key)));
+				}
+				out.props.push((key,value.value()));
+				Ok(())
+			})
+		}else if meta.path.is_ident("rename"){
+			let value = str_value(&meta)?;
+			if out.rename.replace(value.value()).is_some(){
+				return Err(Error::new_spanned(&value,format!("`#[{}(rename = \"...\")]` is given more than once on a variant",ATTR_NAME)));
+			}
+			Ok(())
+		}else if let (Some(name),true) = (meta.path.get_ident(),meta.input.peek(Token![=])){
+			//Checked against the declared associated values by `check`
+			let name = name.clone();
+			let value: Expr = meta.value()?.parse()?;
+			if out.assoc_values.iter().any(|(other,_)| *other == name){
+				return Err(Error::new_spanned(&name,format!("the associated value `{}` is given more than once on a variant",name)));
+			}
+			out.assoc_values.push((name,value));
+			Ok(())
+		}else{
+			Err(meta.error(format!("unknown option `{}` in `#[{}(...)]` on a variant",item_name(&meta),ATTR_NAME)))
+		}
+	})?;
+	Ok(out)
+}
+
+/// Checks the options of the enum and of every variant, including that every associated value given to a variant is declared on the enum
+pub fn check(attrs: &[Attribute],variants: &[Variant]) -> syn::Result<()>{
+	let enum_attrs = enum_attrs(attrs)?;
+	let mut error: Option<Error> = None;
+	for variant in variants{
+		let errors: Vec<Error> = match variant_attrs(&variant.attrs){
+			Ok(variant_attrs) => variant_attrs.assoc_values.iter()
+				.filter(|&(name,_)| !enum_attrs.assoc.iter().any(|field| field.name == *name))
+				.map(|(name,_)| Error::new_spanned(name,format!("unknown option `{}` in `#[{}(...)]` on a variant",name,ATTR_NAME)))
+				.collect(),
+			Err(e) => vec![e],
+		};
+		for e in errors{match error{
+			Some(ref mut error) => error.combine(e),
+			None => error = Some(e),
+		}}
+	}
+	match error{
+		Some(error) => Err(error),
+		None => Ok(()),
+	}
+}
@@ -0,0 +1,107 @@
+//! Conversion of identifiers between case conventions.
+
+#[cfg(feature = "no_std_compile")]use alloc::string::String;
+#[cfg(feature = "no_std_compile")]use alloc::vec::Vec;
+
+/// A case convention given by `#[enum_traits(rename_all = "...")]`
+#[derive(Clone,Copy,Debug,PartialEq,Eq)]
+pub enum RenameRule{
+	/// `lowercase`
+	Lower,
+	/// `UPPERCASE`
+	Upper,
+	/// `PascalCase`
+	Pascal,
+	/// `camelCase`
+	Camel,
+	/// `snake_case`
+	Snake,
+	/// `SCREAMING_SNAKE_CASE`
+	ScreamingSnake,
+	/// `kebab-case`
+	Kebab,
+	/// `SCREAMING-KEBAB-CASE`
+	ScreamingKebab,
+}
+
+impl RenameRule{
+	pub const NAMES: &'static [(&'static str,RenameRule)] = &[
+		("lowercase"           ,RenameRule::Lower),
+		("UPPERCASE"           ,RenameRule::Upper),
+		("PascalCase"          ,RenameRule::Pascal),
+		("camelCase"           ,RenameRule::Camel),
+		("snake_case"          ,RenameRule::Snake),
+		("SCREAMING_SNAKE_CASE",RenameRule::ScreamingSnake),
+		("kebab-case"          ,RenameRule::Kebab),
+		("SCREAMING-KEBAB-CASE",RenameRule::ScreamingKebab),
+	];
+
+	pub fn from_name(name: &str) -> Option<RenameRule>{
+		RenameRule::NAMES.iter().find(|&&(rule_name,_)| rule_name == name).map(|&(_,rule)| rule)
+	}
+
+	/// Converts an identifier written in `PascalCase`, `snake_case` or a mix of them to this case convention
+	pub fn apply(self,ident: &str) -> String{
+		let words = words(ident);
+		match self{
+			RenameRule::Lower          => words.concat().to_lowercase(),
+			RenameRule::Upper          => words.concat().to_uppercase(),
+			RenameRule::Pascal         => words.iter().map(|word| capitalize(word)).collect(),
+			RenameRule::Camel          => words.iter().enumerate().map(|(i,word)| if i==0{word.to_lowercase()}else{capitalize(word)}).collect(),
+			RenameRule::Snake          => words.join("_").to_lowercase(),
+			RenameRule::ScreamingSnake => words.join("_").to_uppercase(),
+			RenameRule::Kebab          => words.join("-").to_lowercase(),
+			RenameRule::ScreamingKebab => words.join("-").to_uppercase(),
+		}
+	}
+}
+
+fn capitalize(word: &str) -> String{
+	let mut chars = word.chars();
+	match chars.next(){
+		Some(first) => first.to_uppercase().chain(chars.flat_map(char::to_lowercase)).collect(),
+		None        => String::new(),
+	}
+}
+
+/// Splits an identifier into words.
+/// A new word starts at an uppercase letter following a lowercase letter or a digit (`RobotArm`, `Md5Hash`), at the last uppercase letter of an acronym followed by a lowercase letter (`HTTPServer`), and after every character that is not alphanumeric (`robot_arm`).
+/// Digits belong to the word before them (`Http2Server` is `Http2` and `Server`).
+pub fn words(ident: &str) -> Vec<&str>{
+	let mut words = Vec::new();
+	let chars: Vec<(usize,char)> = ident.char_indices().collect();
+	let mut start: Option<usize> = None;
+
+	for (i,&(byte_index,c)) in chars.iter().enumerate(){
+		if !c.is_alphanumeric(){
+			if let Some(start) = start.take(){
+				words.push(&ident[start..byte_index]);
+			}
+			continue;
+		}
+
+		if let Some(word_start) = start{
+			let prev = chars[i-1].1;
+			let next = chars.get(i+1).map(|&(_,c)| c);
+			let boundary = c.is_uppercase() && (
+				prev.is_lowercase() || prev.is_numeric() ||
+				(prev.is_uppercase() && next.is_some_and(char::is_lowercase))
+			);
+			if boundary{
+				words.push(&ident[word_start..byte_index]);
+				start = Some(byte_index);
+			}
+		}else{
+			start = Some(byte_index);
+		}
+	}
+	if let Some(start) = start{
+		words.push(&ident[start..]);
+	}
+	words
+}
+
+/// Converts an identifier or a name to `snake_case` usable as a part of an identifier
+pub fn to_snake_case(ident: &str) -> String{
+	RenameRule::Snake.apply(ident)
+}
@@ -0,0 +1,75 @@
+//! Parsing of the format strings given by `#[enum_traits(display = "...")]`.
+
+#[cfg(feature = "no_std_compile")]use alloc::string::{String,ToString};
+
+/// Rewrites a format string, replacing every argument (`0` in `{0}` and `name` in `{name}`) by the name returned by `rename`.
+/// This includes the arguments in the format specs, used as widths and precisions (`{0:1$}`).
+/// Escaped braces (`{{` and `}}`) are kept as they are.
+pub fn rewrite<F>(template: &str,mut rename: F) -> Result<String,String>
+	where F: FnMut(&str) -> Result<String,String>
+{
+	let mut out = String::with_capacity(template.len());
+	let mut rest = template;
+
+	while let Some(start) = rest.find(['{','}']){
+		out.push_str(&rest[..start]);
+		let (brace,after) = rest[start..].split_at(1);
+		if after.starts_with(brace){
+			out.push_str(brace);
+			out.push_str(brace);
+			rest = &after[1..];
+			continue;
+		}
+		if brace == "}"{
+			return Err("unmatched `}` in the format string. Write `}}` to format a `}`".to_string());
+		}
+
+		let end = after.find('}').ok_or_else(|| "unclosed `{` in the format string. Write `{{` to format a `{`".to_string())?;
+		let (arg,spec) = match after[..end].find(':'){
+			Some(colon) => (&after[..colon],Some(&after[colon+1..end])),
+			None        => (&after[..end],None),
+		};
+		if arg.is_empty(){
+			return Err("implicit arguments (`{}`) are not supported in the format string. Refer to the fields by `{0}` or `{name}`".to_string());
+		}
+
+		out.push('{');
+		out.push_str(&rename(arg)?);
+		if let Some(spec) = spec{
+			out.push(':');
+			out.push_str(&rewrite_spec(spec,&mut rename)?);
+		}
+		out.push('}');
+		rest = &after[end+1..];
+	}
+	out.push_str(rest);
+	Ok(out)
+}
+
+/// Rewrites the arguments followed by `$` in a format spec
+fn rewrite_spec<F>(spec: &str,rename: &mut F) -> Result<String,String>
+	where F: FnMut(&str) -> Result<String,String>
+{
+	let mut out = String::with_capacity(spec.len());
+	let mut word_start: Option<usize> = None;
+	for (i,c) in spec.char_indices(){
+		if c.is_alphanumeric() || c == '_'{
+			if word_start.is_none(){
+				word_start = Some(i);
+			}
+			continue;
+		}
+		if let Some(start) = word_start.take(){
+			if c == '$'{
+				out.push_str(&rename(&spec[start..i])?);
+			}else{
+				out.push_str(&spec[start..i]);
+			}
+		}
+		out.push(c);
+	}
+	if let Some(start) = word_start{
+		out.push_str(&spec[start..]);
+	}
+	Ok(out)
+}
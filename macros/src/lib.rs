@@ -1,106 +1,373 @@
 #![allow(non_snake_case)]
+#![allow(clippy::tabs_in_doc_comments)]
 
 #![cfg_attr(feature = "no_std_compile" ,no_std)]
-#![cfg_attr(feature = "no_std_compile" ,feature(collections))]
 
-#[cfg(feature = "no_std_compile")]extern crate collections;
+#[cfg(feature = "no_std_compile")]#[macro_use]extern crate alloc;
+extern crate proc_macro;
+extern crate proc_macro2;
+#[macro_use]
 extern crate syn;
 #[macro_use]
 extern crate quote;
 
-extern crate proc_macro;
-use proc_macro::TokenStream;
-
 #[cfg(not(feature = "no_std_compile"))]use  std::{cmp,iter};
-#[cfg(not(feature = "no_std_compile"))]use  std::ascii::AsciiExt;
-#[cfg(not(feature = "no_std_compile"))]use  std::iter::FromIterator;
 #[cfg(feature = "no_std_compile")     ]use core::{cmp,iter};
-#[cfg(feature = "no_std_compile")     ]use core::iter::FromIterator;
-#[cfg(feature = "no_std_compile")     ]use collections::string::{String,ToString};
-#[cfg(feature = "no_std_compile")     ]use collections::vec::Vec;
-use syn::{Attribute,Body,Expr,ExprKind,Ident,Lit,IntTy,MacroInput,Variant,VariantData};
-use quote::Tokens;
+#[cfg(feature = "no_std_compile")     ]use alloc::string::{String,ToString};
+#[cfg(feature = "no_std_compile")     ]use alloc::vec::Vec;
+use proc_macro2::{Literal,Span,TokenStream};
+use syn::{Attribute,Data,DeriveInput,Error,Expr,Fields,Ident,Lit,LitStr,Variant};
+use syn::ext::IdentExt;
+
+mod attr;
+mod case;
+mod display;
 
 fn minimum_type_from_value(value: usize) -> Ident{
-	if value <= u8::max_value() as usize{
-		Ident::from("u8")
-	}else if value <= u16::max_value() as usize{
-		Ident::from("u16")
-	}else if value <= u32::max_value() as usize{
-		Ident::from("u32")
-	}else if value <= u64::max_value() as usize{
-		Ident::from("u64")
-	}else{
-		Ident::from("usize")
+	Ident::new(
+		if value <= u8::MAX as usize{"u8"}
+		else if value <= u16::MAX as usize{"u16"}
+		else if value <= u32::MAX as usize{"u32"}
+		else if value <= u64::MAX as usize{"u64"}
+		else{"usize"},
+		Span::call_site()
+	)
+}
+
+/// The smallest primitive integer type containing every value in the range `min..=max`
+fn minimum_type_from_range(min: i128,max: i128) -> Ident{
+	let fits = |ty_min: i128,ty_max: i128| ty_min <= min && max <= ty_max;
+	Ident::new(
+		if fits(0,u8::MAX as i128){"u8"}
+		else if fits(0,u16::MAX as i128){"u16"}
+		else if fits(0,u32::MAX as i128){"u32"}
+		else if fits(0,u64::MAX as i128){"u64"}
+		else if fits(i8::MIN as i128,i8::MAX as i128){"i8"}
+		else if fits(i16::MIN as i128,i16::MAX as i128){"i16"}
+		else if fits(i32::MIN as i128,i32::MAX as i128){"i32"}
+		else{"i64"},
+		Span::call_site()
+	)
+}
+
+/// Evaluates a constant expression consisting of integer literals and arithmetic.
+/// Returns `None` when the expression refers to something else (e.g. constants or casts), depends on the type of the expression (`!`), or overflows.
+fn eval_const_expr(expr: &Expr) -> Option<i128>{
+	use syn::{BinOp,UnOp};
+
+	let value = match *expr{
+		Expr::Lit(ref expr) => match expr.lit{
+			Lit::Int(ref lit) => lit.base10_parse::<u64>().ok()? as i128,
+			_ => return None
+		},
+		Expr::Paren(ref expr) => eval_const_expr(&expr.expr)?,
+		Expr::Group(ref expr) => eval_const_expr(&expr.expr)?,
+		Expr::Unary(ref expr) => match expr.op{
+			UnOp::Neg(_) => eval_const_expr(&expr.expr)?.checked_neg()?,
+			_ => return None
+		},
+		Expr::Binary(ref expr) => {
+			let (l,r) = (eval_const_expr(&expr.left)?,eval_const_expr(&expr.right)?);
+			match expr.op{
+				BinOp::Add(_)    => l.checked_add(r)?,
+				BinOp::Sub(_)    => l.checked_sub(r)?,
+				BinOp::Mul(_)    => l.checked_mul(r)?,
+				BinOp::Div(_)    => l.checked_div(r)?,
+				BinOp::Rem(_)    => l.checked_rem(r)?,
+				BinOp::BitAnd(_) => l & r,
+				BinOp::BitOr(_)  => l | r,
+				BinOp::BitXor(_) => l ^ r,
+				BinOp::Shl(_) if (0..64).contains(&r) => l.checked_mul(1 << r)?,
+				BinOp::Shr(_) if (0..64).contains(&r) => l >> r,
+				_ => return None
+			}
+		}
+		_ => return None
+	};
+
+	//Only values representable by a literal of at most 64 bits are used
+	if (i64::MIN as i128..=u64::MAX as i128).contains(&value){Some(value)}else{None}
+}
+
+/// The values of the discriminants of the variants, or `None` for the ones that could not be evaluated.
+/// Variants without an explicit discriminant have the discriminant of the previous variant plus one, starting from zero.
+fn discriminant_values(data: &[Variant]) -> Vec<Option<i128>>{
+	let mut previous = Some(-1);
+	data.iter().map(|variant|{
+		let value = match variant.discriminant{
+			Some((_,ref expr)) => eval_const_expr(expr),
+			None => previous.and_then(|previous: i128| previous.checked_add(1)),
+		};
+		previous = value;
+		value
+	}).collect()
+}
+
+/// The smallest type containing the discriminants of an enum without a `repr` attribute.
+/// When a discriminant could not be evaluated, this is `isize` which is the type used by the compiler.
+fn minimum_type_from_discriminants(values: &[Option<i128>]) -> Ident{
+	if values.iter().any(Option::is_none){
+		return Ident::new("isize",Span::call_site());
 	}
+	let min = values.iter().filter_map(|&value| value).min().unwrap_or(0);
+	let max = values.iter().filter_map(|&value| value).max().unwrap_or(0);
+	minimum_type_from_range(min,max)
+}
+
+/// An integer literal, negated when negative
+fn int_lit(value: i128) -> TokenStream{
+	let lit = Literal::u128_unsuffixed(value.unsigned_abs());
+	if value < 0{quote! { -#lit }}else{quote! { #lit }}
 }
 
 fn type_from_repr_attr<'i,I>(attrs: I) -> Option<Ident>
 	where I: Iterator<Item = &'i Attribute>
 {
-	use syn::{MetaItem,NestedMetaItem};
+	use syn::Meta;
+	use syn::punctuated::Punctuated;
 
-	for attr in attrs{match attr.value{
-		MetaItem::List(ref ident,ref content) if ident=="repr" => match &content[0]{
-			&NestedMetaItem::MetaItem(MetaItem::Word(ref ty)) if ty!="C" => return Some(ty.clone()),
+	const PRIMITIVE_TYPES: &[&str] = &["u8","u16","u32","u64","u128","usize","i8","i16","i32","i64","i128","isize"];
+
+	for attr in attrs.filter(|attr| attr.path().is_ident("repr")){
+		//Malformed representations are reported by the compiler
+		let items = match attr.parse_args_with(Punctuated::<Meta,Token![,]>::parse_terminated){
+			Ok(items) => items,
+			Err(_) => continue,
+		};
+		//The primitive type may be combined with other representations, for example `#[repr(C,u8)]`
+		for item in items{match item{
+			Meta::Path(ref path) => match path.get_ident(){
+				Some(ty) if PRIMITIVE_TYPES.contains(&ty.to_string().as_str()) => return Some(ty.clone()),
+				_ => continue,
+			},
 			_ => continue,
+		}}
+	}
+	None
+}
+
+/// Collects the values, or combines every error into one
+fn collect_results<T,I>(results: I) -> syn::Result<Vec<T>>
+	where I: IntoIterator<Item = syn::Result<T>>
+{
+	let mut values = Vec::new();
+	let mut error: Option<Error> = None;
+	for result in results{match result{
+		Ok(value) => values.push(value),
+		Err(e) => match error{
+			Some(ref mut error) => error.combine(e),
+			None => error = Some(e),
 		},
-		_ => continue,
 	}}
-	None
+	match error{
+		Some(error) => Err(error),
+		None => Ok(values),
+	}
 }
 
-fn variant_unit_ident<'v>(variant: &'v Variant,derive_name: &'static str) -> &'v Ident{match variant.data{
-	VariantData::Unit => {
-		&variant.ident
+fn variant_unit_ident<'v>(variant: &'v Variant,derive_name: &'static str) -> syn::Result<&'v Ident>{match variant.fields{
+	Fields::Unit => {
+		Ok(&variant.ident)
 	}
-	_ => panic!("`derive({})` may only be applied to enum items with no fields",derive_name)
+	_ => Err(Error::new_spanned(variant,format!("`derive({})` may only be applied to enum items with no fields, but the variant `{}` has fields",derive_name,variant.ident)))
 }}
 
-fn derive_enum<F>(input: TokenStream,gen_impl: F) -> TokenStream
-	where F: FnOnce(&Ident,&MacroInput,&Vec<Variant>,Ident) -> Tokens
+/// An expression constructing the variant.
+/// Variants with fields are constructed by `#[enum_traits(construct = "...")]` on the variant, or by `Default` for every field when `#[enum_traits(default_fields)]` is on the enum.
+fn variant_constructor(ident: &Ident,variant: &Variant,enum_attrs: &attr::EnumAttrs,std: &Ident,derive_name: &'static str) -> syn::Result<TokenStream>{
+	let variant_ident = &variant.ident;
+	if let Some(construct) = attr::variant_attrs(&variant.attrs)?.construct{
+		return Ok(quote! { #construct });
+	}
+	match variant.fields{
+		Fields::Unit => Ok(quote! { #ident::#variant_ident }),
+		Fields::Unnamed(ref fields) if enum_attrs.default_fields => {
+			let defaults = fields.unnamed.iter().map(|_| quote! { ::#std::default::Default::default() });
+			Ok(quote! { #ident::#variant_ident( #( #defaults ),* ) })
+		}
+		Fields::Named(ref fields) if enum_attrs.default_fields => {
+			let names = fields.named.iter().map(|field| &field.ident);
+			Ok(quote! { #ident::#variant_ident{ #( #names: ::#std::default::Default::default() ),* } })
+		}
+		_ => Err(Error::new_spanned(variant,format!("`derive({})` cannot construct the variant `{}` because it has fields. Use `#[enum_traits(default_fields)]` on the enum or `#[enum_traits(construct = \"...\")]` on the variant to construct it",derive_name,variant_ident)))
+	}
+}
+
+/// Whether `variant_constructor` is able to construct the variant
+fn is_constructible(variant: &Variant,enum_attrs: &attr::EnumAttrs,variant_attrs: &attr::VariantAttrs) -> bool{
+	match variant.fields{
+		Fields::Unit => true,
+		_ => enum_attrs.default_fields || variant_attrs.construct.is_some(),
+	}
+}
+
+/// The variants that are iterated over and have an index, in the defined order.
+/// These are the variants not marked by `#[enum_traits(skip(iter))]`.
+fn indexed_variants(data: &[Variant]) -> syn::Result<Vec<&Variant>>{
+	let skipped = collect_results(data.iter().map(|variant| attr::variant_attrs(&variant.attrs).map(|attrs| attrs.skip_iter)))?;
+	Ok(data.iter().zip(skipped).filter(|&(_,skipped)| !skipped).map(|(variant,_)| variant).collect())
+}
+
+/// Checks that no variant is marked by `#[enum_traits(skip(iter))]`, for derives where every variant has an index
+fn check_no_skipped_variants(data: &[Variant],derive_name: &'static str) -> syn::Result<()>{
+	collect_results(data.iter().map(|variant| attr::variant_attrs(&variant.attrs).and_then(|variant_attrs|{
+		if variant_attrs.skip_iter{
+			Err(Error::new_spanned(variant,format!("`derive({})`: The variant `{}` is skipped by `#[enum_traits(skip)]` and has no index, but every variant must have one",derive_name,variant.ident)))
+		}else{
+			Ok(())
+		}
+	}))).map(|_| ())
+}
+
+/// The name of the variant converted to `snake_case`, used in the names of the functions of the variant
+fn variant_fn_name(variant: &Variant,variant_attrs: &attr::VariantAttrs,enum_attrs: &attr::EnumAttrs) -> String{
+	case::to_snake_case(&variant_attrs.name(enum_attrs,&variant.ident))
+}
+
+/// The identifier of a function of the variant, spanned on the variant.
+/// Keywords (`type` from the variant `Type`) are raw identifiers.
+fn variant_fn_ident(name: &str,variant: &Variant,derive_name: &'static str) -> syn::Result<Ident>{
+	match (syn::parse_str::<Ident>(name),syn::parse_str::<Ident>(&format!("r#{}",name))){
+		(Ok(_),_) => Ok(Ident::new(name,variant.ident.span())),
+		(_,Ok(_)) => Ok(Ident::new_raw(name,variant.ident.span())),
+		_ => Err(Error::new_spanned(variant,format!("`derive({})`: `{}` is not a valid name for a function of the variant `{}`",derive_name,name,variant.ident))),
+	}
+}
+
+/// Checks that no two variants have functions with the same name
+fn check_fn_collisions(fns: &[(Ident,&Variant)],derive_name: &'static str) -> syn::Result<()>{
+	collect_results(fns.iter().enumerate().map(|(i,&(ref fn_ident,variant))|{
+		match fns[..i].iter().find(|&&(ref other_fn_ident,other_variant)| other_fn_ident == fn_ident && other_variant.ident != variant.ident){
+			Some(&(_,other_variant)) => Err(Error::new_spanned(variant,format!("`derive({})`: The function `{}` of the variant `{}` has the same name as a function of the variant `{}`",derive_name,fn_ident,variant.ident,other_variant.ident))),
+			None => Ok(()),
+		}
+	})).map(|_| ())
+}
+
+/// The names of the identifiers and lifetimes (`'a`) in the tokens, which includes the generic parameters the tokens refer to
+fn token_names(tokens: TokenStream,out: &mut Vec<String>){
+	let mut lifetime = false;
+	for token in tokens{
+		match token{
+			proc_macro2::TokenTree::Group(group) => token_names(group.stream(),out),
+			proc_macro2::TokenTree::Ident(ident) => out.push(if lifetime{format!("'{}",ident)}else{ident.to_string()}),
+			proc_macro2::TokenTree::Punct(ref punct) if punct.as_char() == '\'' => {
+				lifetime = true;
+				continue;
+			}
+			_ => {}
+		}
+		lifetime = false;
+	}
+}
+
+/// The generics of the enum that the fields of the variant refer to.
+/// Bounds, defaults and predicates referring to other generic parameters are removed.
+fn variant_generics(generics: &syn::Generics,variant: &Variant) -> syn::Generics{
+	use syn::GenericParam;
+
+	fn param_name(param: &GenericParam) -> String{match *param{
+		GenericParam::Lifetime(ref param) => param.lifetime.to_string(),
+		GenericParam::Type(ref param) => param.ident.to_string(),
+		GenericParam::Const(ref param) => param.ident.to_string(),
+	}}
+
+	let mut names = Vec::new();
+	for field in variant.fields.iter(){
+		let ty = &field.ty;
+		token_names(quote!{#ty},&mut names);
+	}
+	let kept: Vec<String> = generics.params.iter().map(param_name).filter(|name| names.contains(name)).collect();
+	let removed: Vec<String> = generics.params.iter().map(param_name).filter(|name| !kept.contains(name)).collect();
+
+	//Whether the tokens only refer to the kept parameters
+	let only_kept = |tokens: TokenStream|{
+		let mut names = Vec::new();
+		token_names(tokens,&mut names);
+		!names.iter().any(|name| removed.contains(name))
+	};
+
+	let mut out = generics.clone();
+	out.params = generics.params.iter().filter(|param| kept.contains(&param_name(param))).cloned().map(|mut param|{
+		match param{
+			GenericParam::Lifetime(ref mut param) => {
+				param.bounds = param.bounds.iter().filter(|bound| only_kept(quote!{#bound})).cloned().collect();
+			}
+			GenericParam::Type(ref mut param) => {
+				param.bounds = param.bounds.iter().filter(|bound| only_kept(quote!{#bound})).cloned().collect();
+				if param.default.as_ref().is_some_and(|default| !only_kept(quote!{#default})){
+					param.default = None;
+				}
+			}
+			GenericParam::Const(ref mut param) => {
+				if param.default.as_ref().is_some_and(|default| !only_kept(quote!{#default})){
+					param.default = None;
+				}
+			}
+		}
+		param
+	}).collect();
+	if let Some(ref mut where_clause) = out.where_clause{
+		where_clause.predicates = where_clause.predicates.iter().filter(|predicate| only_kept(quote!{#predicate})).cloned().collect();
+	}
+	out
+}
+
+/// Folds the case of a string for case insensitive comparisons.
+/// Must be consistent with the folding in the generated code.
+fn fold_case(str: &str) -> String{
+	str.chars().flat_map(char::to_lowercase).collect()
+}
+
+fn derive_enum<F>(input: proc_macro::TokenStream,derive_name: &'static str,gen_impl: F) -> proc_macro::TokenStream
+	where F: FnOnce(&Ident,&DeriveInput,&[Variant],&Ident) -> syn::Result<TokenStream>
 {
-	let input = input.to_string();
-	let ast = syn::parse_macro_input(&input).unwrap();
-	#[cfg(not(feature = "no_std"))]let std = Ident::new("std");
-	#[cfg(feature = "no_std")     ]let std = Ident::new("core");
+	let ast = parse_macro_input!(input as DeriveInput);
+	#[cfg(not(feature = "no_std"))]let std = Ident::new("std" ,Span::call_site());
+	#[cfg(feature = "no_std")     ]let std = Ident::new("core",Span::call_site());
+
+	let result = match ast.data{
+		Data::Enum(ref data) => {
+			let variants: Vec<Variant> = data.variants.iter().cloned().collect();
+			attr::check(&ast.attrs,&variants).and_then(|_| gen_impl(&ast.ident,&ast,&variants,&std))
+		}
+		_ => Err(Error::new_spanned(&ast.ident,format!("`derive({})` may only be applied to enum items",derive_name)))
+	};
 
-	let quote_tokens = match ast.body{
-		Body::Enum(ref data) => gen_impl(&ast.ident,&ast,data,std),
-		_ => panic!("`derive(Enum*)` may only be applied to enum items")
-	}.to_string();
+	result.unwrap_or_else(compile_error).into()
+}
 
-	quote_tokens.parse().unwrap()
+/// `compile_error!` invocations spanned on the location of every error.
+/// Unlike `Error::into_compile_error`, the macro is not referred to by `::core`, which is not in scope in crates of the 2015 edition.
+fn compile_error(error: Error) -> TokenStream{
+	error.into_iter().map(|error|{
+		let message = error.to_string();
+		quote_spanned!{error.span()=> compile_error!{#message}}
+	}).collect()
 }
 
 #[allow(dead_code)]
-fn minimum_type_containing_enum(item: &MacroInput,data: &Vec<Variant>) -> syn::Ident{//TODO: Maybe useful to export?
+fn minimum_type_containing_enum(item: &DeriveInput,data: &[Variant]) -> syn::Ident{//TODO: Maybe useful to export?
 	//First, check if there's a repr attribute
 	type_from_repr_attr(item.attrs.iter())
-	.unwrap_or_else(||
-		//Second, use the maximum value of an explicit discriminant or the length of the enum (depending on which is the greatest)
-		minimum_type_from_value(match data.iter().filter_map(|variant| match variant.discriminant{
-				Some(syn::ConstExpr::Lit(syn::Lit::Int(discrimimant,_))) => Some(discrimimant),
-				_ => None
-			}).max(){
-				Some(max) => cmp::max(cmp::max(data.len(),1)-1 , max as usize),
-				//Third, use the length of the enum
-				_ => cmp::max(data.len(),1)-1
-			}
-		)
-	)
+	//Second, use the range of the discriminants (which are the indices of the variants when there are no explicit discriminants)
+	.unwrap_or_else(|| minimum_type_from_discriminants(&discriminant_values(data)))
 }
 
 /// Implements `enum_traits::Len`, a constant that indicates the number of variants of an enum.
+/// Also implements `enum_traits::LenArray`, an array type with one element for every variant.
 ///
 /// # Requirements
 /// - The derived item is an enum
 ///
+/// # Attributes
+/// - `#[enum_traits(skip)]` or `#[enum_traits(skip(iter))]` on a variant: The variant is not counted.
+///
 /// # Examples
 ///
 /// ```rust
-/// # #![feature(associated_consts)]
 /// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
 /// # extern crate enum_traits;
 /// # use enum_traits::*;
 /// # fn main(){
@@ -113,6 +380,9 @@ fn minimum_type_containing_enum(item: &MacroInput,data: &Vec<Variant>) -> syn::I
 /// }{
 /// 	#[derive(EnumLen)]enum T{A,B,C}
 /// 	assert_eq!(3,T::len());
+/// 	assert_eq!(3,T::LEN);
+/// 	let array: [u8; T::LEN] = [0; T::LEN];
+/// 	assert_eq!(3,array.len());
 /// }{
 /// 	#[derive(EnumLen)]enum T{A,B,C,D,E,F,G}
 /// 	assert_eq!(7,T::len());
@@ -128,31 +398,44 @@ fn minimum_type_containing_enum(item: &MacroInput,data: &Vec<Variant>) -> syn::I
 /// }
 /// # }
 /// ```
-#[proc_macro_derive(EnumLen)]
-pub fn derive_EnumLen(input: TokenStream) -> TokenStream{ //TODO: Consider allowing structs. Number of variants of struct is always 1
-	fn gen_impl(ident: &Ident,item: &MacroInput,data: &Vec<Variant>, _: Ident) -> Tokens{
+#[proc_macro_derive(EnumLen,attributes(enum_traits))]
+pub fn derive_EnumLen(input: proc_macro::TokenStream) -> proc_macro::TokenStream{ //TODO: Consider allowing structs. Number of variants of struct is always 1
+	fn gen_impl(ident: &Ident,item: &DeriveInput,data: &[Variant],std: &Ident) -> syn::Result<TokenStream>{
 		let (impl_generics,ty_generics,where_clause) = item.generics.split_for_impl();
-		let len = data.len();
+		let len = indexed_variants(data)?.len();
 
-		#[cfg(not(feature = "nightly"))]
-		quote!{
+		let impl_len = quote!{
 			#[automatically_derived]
 			#[allow(unused_attributes)]
 			impl #impl_generics ::enum_traits::Len for #ident #ty_generics #where_clause{
-				fn len() -> usize{#len}
+				const LEN: usize = #len;
 			}
-		}
+		};
+
+		let value_ident = Ident::new("EnumTraitsValue",Span::call_site());
+		let array_generics = {
+			let mut generics = item.generics.clone();
+			generics.params.push(syn::GenericParam::Type(syn::TypeParam::from(value_ident.clone())));
+			generics
+		};
+		let (array_impl_generics,_,_) = array_generics.split_for_impl();
+
+		Ok(quote!{
+			#impl_len
 
-		#[cfg(feature = "nightly")]
-		quote!{
 			#[automatically_derived]
 			#[allow(unused_attributes)]
-			impl #impl_generics ::enum_traits::Len for #ident #ty_generics #where_clause{
-				const LEN: usize = #len;
+			impl #array_impl_generics ::enum_traits::LenArray<#value_ident> for #ident #ty_generics #where_clause{
+				type Array = [#value_ident; #len];
+
+				#[inline]
+				fn array_from_fn<F: FnMut(usize) -> #value_ident>(f: F) -> Self::Array{
+					::#std::array::from_fn(f)
+				}
 			}
-		}
+		})
 	}
-	derive_enum(input,gen_impl)
+	derive_enum(input,"EnumLen",gen_impl)
 }
 
 /// Implements `enum_traits::Ends`, two constructors that constructs the first and the last variant of an enum in the defined order.
@@ -160,14 +443,20 @@ pub fn derive_EnumLen(input: TokenStream) -> TokenStream{ //TODO: Consider allow
 /// # Requirements
 /// - The derived item is an enum
 /// - The enum has at least one variant
-/// - The enum's first variant is an unit variant
-/// - The enum's last variant is an unit variant
+/// - The enum's first variant is an unit variant or constructible (see below)
+/// - The enum's last variant is an unit variant or constructible (see below)
+///
+/// # Attributes
+/// - `#[enum_traits(default_fields)]` on the enum: Variants with fields are constructed using `Default::default()` for every field.
+/// - `#[enum_traits(construct = "...")]` on a variant: The expression constructing the variant, overriding `default_fields`.
+/// - `#[enum_traits(skip)]` or `#[enum_traits(skip(iter))]` on a variant: The variant is never the first or the last variant.
+///   The requirements above then apply to the variants that are not skipped.
 ///
 /// # Examples
 ///
 /// ```rust
-/// # #![feature(associated_consts)]
 /// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
 /// # extern crate enum_traits;
 /// # use enum_traits::*;
 /// # fn main(){
@@ -202,35 +491,43 @@ pub fn derive_EnumLen(input: TokenStream) -> TokenStream{ //TODO: Consider allow
 /// }
 /// # }
 /// ```
-#[proc_macro_derive(EnumEnds)]
-pub fn derive_EnumEnds(input: TokenStream) -> TokenStream{
-	fn gen_impl(ident: &Ident,item: &MacroInput,data: &Vec<Variant>, _: Ident) -> Tokens{
+#[proc_macro_derive(EnumEnds,attributes(enum_traits))]
+pub fn derive_EnumEnds(input: proc_macro::TokenStream) -> proc_macro::TokenStream{
+	fn gen_impl(ident: &Ident,item: &DeriveInput,data: &[Variant],std: &Ident) -> syn::Result<TokenStream>{
 		let (impl_generics,ty_generics,where_clause) = item.generics.split_for_impl();
-		let variant_first_ident = &data.first().expect("`derive(EnumEnds)` may only be applied to non-empty enums").ident;
-		let variant_last_ident  = &data.last().expect("`derive(EnumEnds)` may only be applied to non-empty enums").ident;
+		let enum_attrs = attr::enum_attrs(&item.attrs)?;
+		let data = indexed_variants(data)?;
+		let (first,last) = match (data.first(),data.last()){
+			(Some(first),Some(last)) => (first,last),
+			_ => return Err(Error::new_spanned(ident,"`derive(EnumEnds)` may only be applied to enums with non-skipped variants")),
+		};
+		let first = variant_constructor(ident,first,&enum_attrs,std,"EnumEnds")?;
+		let last  = variant_constructor(ident,last ,&enum_attrs,std,"EnumEnds")?;
 
-		quote!{
+		Ok(quote!{
 			#[automatically_derived]
 			#[allow(unused_attributes)]
 			impl #impl_generics ::enum_traits::Ends for #ident #ty_generics #where_clause{
-				#[inline(always)]fn first() -> Self{#ident::#variant_first_ident}
-				#[inline(always)]fn last()  -> Self{#ident::#variant_last_ident}
+				#[inline(always)]fn first() -> Self{#first}
+				#[inline(always)]fn last()  -> Self{#last}
 			}
-		}
+		})
 	}
-	derive_enum(input,gen_impl)
+	derive_enum(input,"EnumEnds",gen_impl)
 }
 
 /// Implements `enum_traits::ToIndex`, a function that returns the index of a variant of an enum in the defined order.
 ///
 /// # Requirements
 /// - The derived item is an enum
+/// - No variant is marked by `#[enum_traits(skip)]` or `#[enum_traits(skip(iter))]`, because every value must have an index.
+///   `derive(EnumFromIndex)`, `derive(EnumLen)`, `derive(EnumEnds)` and `derive(EnumIter)` accept skipped variants.
 ///
 /// # Examples
 ///
 /// ```rust
-/// # #![feature(associated_consts)]
 /// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
 /// # extern crate enum_traits;
 /// # use enum_traits::*;
 /// # fn main(){
@@ -335,46 +632,62 @@ pub fn derive_EnumEnds(input: TokenStream) -> TokenStream{
 /// }
 /// # }
 /// ```
-#[proc_macro_derive(EnumToIndex)]
-pub fn derive_EnumToIndex(input: TokenStream) -> TokenStream{
-	fn gen_impl(ident: &Ident,item: &MacroInput,data: &Vec<Variant>, _: Ident) -> Tokens{
+///
+/// A skipped variant is reported as an error at the variant:
+///
+/// ```compile_fail
+/// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
+/// # extern crate enum_traits;
+/// # fn main(){}
+/// #[derive(EnumIndex,EnumToIndex)]
+/// enum T{
+/// 	A,
+/// 	#[enum_traits(skip)]
+/// 	Invalid,
+/// }
+/// ```
+#[proc_macro_derive(EnumToIndex,attributes(enum_traits))]
+pub fn derive_EnumToIndex(input: proc_macro::TokenStream) -> proc_macro::TokenStream{
+	fn gen_impl(ident: &Ident,item: &DeriveInput,data: &[Variant],_: &Ident) -> syn::Result<TokenStream>{
 		let (impl_generics,ty_generics,where_clause) = item.generics.split_for_impl();
 
-		let match_arms = data.iter().enumerate().map(|(i,variant)|{
+		check_no_skipped_variants(data,"EnumToIndex")?;
+		let indices: Vec<Literal> = (0..data.len()).map(Literal::usize_unsuffixed).collect();
+
+		let match_arms = data.iter().zip(indices.iter()).map(|(variant,i)|{
 			let variant_ident = &variant.ident;
-			let i = Lit::Int(i as u64,IntTy::Unsuffixed);
 
-			match variant.data{
-				VariantData::Unit => {
+			match variant.fields{
+				Fields::Unit => {
 					quote! { &#ident::#variant_ident => #i, }
 				}
-				VariantData::Tuple(_) => {
+				Fields::Unnamed(_) => {
 					quote! { &#ident::#variant_ident(..) => #i, }
 				}
-				VariantData::Struct(_) => {
+				Fields::Named(_) => {
 					quote! { &#ident::#variant_ident{..} => #i, }
 				}
 			}
 		});
 
-		let match_arms_into = data.iter().enumerate().map(|(i,variant)|{
+		let match_arms_into = data.iter().zip(indices.iter()).map(|(variant,i)|{
 			let variant_ident = &variant.ident;
-			let i = Lit::Int(i as u64,IntTy::Unsuffixed);
 
-			match variant.data{
-				VariantData::Unit => {
+			match variant.fields{
+				Fields::Unit => {
 					quote! { #ident::#variant_ident => #i, }
 				}
-				VariantData::Tuple(_) => {
+				Fields::Unnamed(_) => {
 					quote! { #ident::#variant_ident(..) => #i, }
 				}
-				VariantData::Struct(_) => {
+				Fields::Named(_) => {
 					quote! { #ident::#variant_ident{..} => #i, }
 				}
 			}
 		});
 
-		quote!{
+		Ok(quote!{
 			#[automatically_derived]
 			#[allow(unused_attributes)]
 			impl #impl_generics ::enum_traits::ToIndex for #ident #ty_generics #where_clause{
@@ -389,32 +702,77 @@ pub fn derive_EnumToIndex(input: TokenStream) -> TokenStream{
 					}
 				}
 			}
-		}
+		})
 	}
-	derive_enum(input,gen_impl)
+	derive_enum(input,"EnumToIndex",gen_impl)
 }
 
 /// Implements `enum_traits::FromIndex`, a function that maybe returns a variant of an enum from an supposed index in the defined order.
 ///
+/// `from_index_unchecked` is a transmute when the enum has a primitive representation (e.g. `#[repr(u8)]`) and the indices are the same as the discriminants.
+/// Otherwise it is a match where an index out of range is unreachable.
+/// In both cases, the index is checked by a debug assertion.
+///
 /// # Requirements
 /// - The derived item is an enum
-#[proc_macro_derive(EnumFromIndex)]
-pub fn derive_EnumFromIndex(input: TokenStream) -> TokenStream{
-	fn variant_unit_ident(variant: &Variant) -> &Ident{
-		::variant_unit_ident(variant,"EnumFromIndex")
+/// - The enum variants is all unit variants or constructible (see below)
+///
+/// # Attributes
+/// - `#[enum_traits(default_fields)]` on the enum: Variants with fields are constructed using `Default::default()` for every field.
+/// - `#[enum_traits(construct = "...")]` on a variant: The expression constructing the variant, overriding `default_fields`.
+/// - `#[enum_traits(skip)]` or `#[enum_traits(skip(iter))]` on a variant: The variant has no index and is never constructed.
+///   The indices are given in order to the variants that are not skipped, so that they are the same as the positions in `Iterable::variants()` and are less than `Len::len()`.
+///   A skipped variant may have fields. `derive(EnumToIndex)` does not accept skipped variants.
+///
+/// # Examples
+///
+/// A variant with fields is reported as an error at the variant:
+///
+/// ```compile_fail
+/// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
+/// # extern crate enum_traits;
+/// #[derive(EnumIndex,EnumFromIndex)]
+/// enum Shape{Empty,Circle(f32)}
+/// # fn main(){}
+/// ```
+#[proc_macro_derive(EnumFromIndex,attributes(enum_traits))]
+pub fn derive_EnumFromIndex(input: proc_macro::TokenStream) -> proc_macro::TokenStream{
+	fn variant_constructor(ident: &Ident,variant: &Variant,enum_attrs: &attr::EnumAttrs,std: &Ident) -> syn::Result<TokenStream>{
+		::variant_constructor(ident,variant,enum_attrs,std,"EnumFromIndex")
 	}
 
-	fn gen_impl(ident: &Ident,item: &MacroInput,data: &Vec<Variant>, std: Ident) -> Tokens{
+	fn gen_impl(ident: &Ident,item: &DeriveInput,data: &[Variant],std: &Ident) -> syn::Result<TokenStream>{
 		let (impl_generics,ty_generics,where_clause) = item.generics.split_for_impl();
+		let enum_attrs = attr::enum_attrs(&item.attrs)?;
 
-		fn match_arm_transform(ident: &Ident,(i,variant_ident): (usize,&Ident)) -> Tokens{
-			let i = Lit::Int(i as u64,IntTy::Unsuffixed);
-			quote! { #i => #ident::#variant_ident, }
+		fn match_arm_transform((i,constructor): (usize,&TokenStream)) -> TokenStream{
+			let i = Literal::usize_unsuffixed(i);
+			quote! { #i => #constructor, }
 		}
-		let match_arms1 = data.iter().map(variant_unit_ident).enumerate().map(|arg| match_arm_transform(ident,arg));
-		let match_arms2 = data.iter().map(variant_unit_ident).enumerate().map(|arg| match_arm_transform(ident,arg));
+		//The indices are the same as the discriminants when every variant is an unit variant, no variant is skipped and there are no explicit discriminants
+		let indexed = indexed_variants(data)?;
+		let contiguous_repr = type_from_repr_attr(item.attrs.iter()).filter(|_|
+			indexed.len() == data.len() &&
+			data.iter().all(|variant| match variant.fields{Fields::Unit => variant.discriminant.is_none() , _ => false})
+		);
+
+		let constructors = collect_results(indexed.iter().map(|variant| variant_constructor(ident,variant,&enum_attrs,std)))?;
+		let match_arms1 = constructors.iter().enumerate().map(match_arm_transform);
+
+		let fn_from_index_unchecked_body = if let Some(repr) = contiguous_repr{quote!{
+			::#std::mem::transmute::<#repr,Self>(index as #repr)
+		}}else{
+			let match_arms2 = constructors.iter().enumerate().map(match_arm_transform);
+			quote!{
+				match index{
+					#( #match_arms2 )*
+					_ => ::#std::hint::unreachable_unchecked()
+				}
+			}
+		};
 
-		quote!{
+		Ok(quote!{
 			#[automatically_derived]
 			#[allow(unused_attributes)]
 			impl #impl_generics ::enum_traits::FromIndex for #ident #ty_generics #where_clause{
@@ -428,96 +786,165 @@ pub fn derive_EnumFromIndex(input: TokenStream) -> TokenStream{
 
 				#[inline]
 				unsafe fn from_index_unchecked(index: <Self as ::enum_traits::Index>::Type) -> Self{
-					match index{
-						#( #match_arms2 )*
-						_ => ::#std::mem::uninitialized()
-					}
+					debug_assert!(<Self as ::enum_traits::FromIndex>::from_index(index).is_some(),"`FromIndex::from_index_unchecked` was called with an index out of range");
+					#fn_from_index_unchecked_body
 				}
 			}
-		}
+		})
 	}
-	derive_enum(input,gen_impl)
+	derive_enum(input,"EnumFromIndex",gen_impl)
 }
 
 /// Implements `enum_traits::Index`.
+/// Unless given by a `repr` attribute, the type is the smallest unsigned integer type containing the indices of the variants not marked by `#[enum_traits(skip(iter))]`.
 ///
 /// # Requirements
 /// - The derived item is an enum
-#[proc_macro_derive(EnumIndex)]
-pub fn derive_EnumIndex(input: TokenStream) -> TokenStream{
-	fn gen_impl(ident: &Ident,item: &MacroInput,data: &Vec<Variant>, _: Ident) -> Tokens{
+#[proc_macro_derive(EnumIndex,attributes(enum_traits))]
+pub fn derive_EnumIndex(input: proc_macro::TokenStream) -> proc_macro::TokenStream{
+	fn gen_impl(ident: &Ident,item: &DeriveInput,data: &[Variant],_: &Ident) -> syn::Result<TokenStream>{
 		let (impl_generics,ty_generics,where_clause) = item.generics.split_for_impl();
 
 		//Determine which type to use (attribute or number of variants)
 		let ty = type_from_repr_attr(item.attrs.iter())
-			.unwrap_or_else(|| minimum_type_from_value(cmp::max(data.len(),1)-1));
+			.unwrap_or(minimum_type_from_value(cmp::max(indexed_variants(data)?.len(),1)-1));
 
-		quote!{
+		Ok(quote!{
 			#[automatically_derived]
 			#[allow(unused_attributes)]
 			impl #impl_generics ::enum_traits::Index for #ident #ty_generics #where_clause{
 				type Type = #ty;
 			}
-		}
+		})
 	}
-	derive_enum(input,gen_impl)
+	derive_enum(input,"EnumIndex",gen_impl)
 }
 
 /// Creates a struct and implements `enum_traits::Iterable`.
+/// The struct is a double-ended iterator over every variant in the defined order.
+/// It has the same generic parameters and where clause as the enum.
+/// Also implements `enum_traits::Variants` when every iterated variant is an unit variant not given by `#[enum_traits(construct = "...")]`.
 ///
 /// # Requirements
 /// - The derived item is an enum
-/// - The enum variants is all unit variants
-#[proc_macro_derive(EnumIter)]
-pub fn derive_EnumIter(input: TokenStream) -> TokenStream{//TODO: Consider rewriting output (EnumIter may not need Option, but then empty enums are not represented. Are they necessary to include?)
-	fn variant_unit_ident(variant: &Variant) -> &Ident{
-		::variant_unit_ident(variant,"EnumIter")
+/// - The enum variants is all unit variants or constructible (see below)
+///
+/// # Attributes
+/// - `#[enum_traits(default_fields)]` on the enum: Variants with fields are constructed using `Default::default()` for every field.
+/// - `#[enum_traits(construct = "...")]` on a variant: The expression constructing the variant, overriding `default_fields`.
+/// - `#[enum_traits(skip)]` or `#[enum_traits(skip(iter))]` on a variant: The variant is not iterated over. A skipped variant may have fields.
+/// - `#[enum_traits(iter(name = "...",vis = "...",derive(...)))]` on the enum: The created struct.
+///   `name` is its name (by default the name of the enum followed by `Iter`), `vis` is its visibility (by default the visibility of the enum) and `derive` are its derives.
+///   Every option is optional.
+///   The struct and the enum refer to each other in their trait implementations, so the visibility of the struct may neither be more nor less restrictive than the visibility of the enum.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
+/// # extern crate enum_traits;
+/// # use enum_traits::*;
+/// # fn main(){
+/// #[derive(Debug,Eq,PartialEq,EnumIter)]
+/// enum Priority{Low,Normal,High,Critical}
+///
+/// let mut iter = Priority::variants();
+/// assert_eq!(Some(Priority::Low)     ,iter.next());
+/// assert_eq!(Some(Priority::Critical),iter.next_back());
+/// assert_eq!(2                       ,iter.len());
+/// assert_eq!(Some(Priority::High)    ,iter.next_back());
+/// assert_eq!(Some(Priority::Normal)  ,iter.next());
+/// assert_eq!(None                    ,iter.next());
+/// assert_eq!(None                    ,iter.next_back());
+///
+/// assert_eq!(Priority::VARIANTS,&[Priority::Low,Priority::Normal,Priority::High,Priority::Critical]);
+/// # }
+/// ```
+///
+/// ```rust
+/// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
+/// # extern crate enum_traits;
+/// # use enum_traits::*;
+/// # fn main(){
+/// #[derive(Debug,PartialEq,EnumIter,EnumFromVariantName)]
+/// #[enum_traits(default_fields)]
+/// enum Command{
+/// 	Move{dx: i32,dy: i32},
+/// 	#[enum_traits(construct = "Command::Say(String::from(\"Hello\"))")]
+/// 	Say(String),
+/// 	Quit,
+/// }
+///
+/// let menu: Vec<Command> = Command::variants().collect();
+/// assert_eq!(menu,vec![Command::Move{dx: 0,dy: 0},Command::Say(String::from("Hello")),Command::Quit]);
+/// assert_eq!("Move".parse(),Ok(Command::Move{dx: 0,dy: 0}));
+/// # }
+/// ```
+///
+/// ```rust
+/// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
+/// # extern crate enum_traits;
+/// # use enum_traits::*;
+/// # fn main(){
+/// #[derive(Debug,PartialEq,EnumIter)]
+/// #[enum_traits(iter(name = "Seasons",derive(Clone,Debug)))]
+/// enum Season{Spring,Summer,Autumn,Winter}
+///
+/// let mut iter: Seasons = Season::variants();
+/// assert_eq!(iter.nth(1),Some(Season::Summer));
+/// assert_eq!(iter.clone().next(),Some(Season::Autumn));
+/// assert_eq!(iter.count(),2);
+/// # }
+/// ```
+#[proc_macro_derive(EnumIter,attributes(enum_traits))]
+pub fn derive_EnumIter(input: proc_macro::TokenStream) -> proc_macro::TokenStream{
+	fn variant_constructor(ident: &Ident,variant: &Variant,enum_attrs: &attr::EnumAttrs,std: &Ident) -> syn::Result<TokenStream>{
+		::variant_constructor(ident,variant,enum_attrs,std,"EnumIter")
 	}
 
-	fn gen_impl(ident: &Ident,item: &MacroInput,data: &Vec<Variant>, std: Ident) -> Tokens{
+	fn gen_impl(ident: &Ident,item: &DeriveInput,data: &[Variant],std: &Ident) -> syn::Result<TokenStream>{
 		let (impl_generics,ty_generics,where_clause) = item.generics.split_for_impl();
-		let visibility = &item.vis;
-
-		let len = data.len();
-		let last = data.last();
-
-		/*let prev_match_arms = {
-				let iter = data.iter().rev().map(variant_unit_ident);
-				iter.zip(data.iter().rev().map(variant_unit_ident).skip(1))
-			}.map(|(variant_ident1,variant_ident2)|{
-				quote! { &Some(#ident::#variant_ident1) => {self.0 = Some(#ident::#variant_ident2); #ident::#variant_ident2}, }
-			});*/
-
-		let next_match_arms = {
-				let iter = data.iter().map(variant_unit_ident);
-				iter.zip(data.iter().map(variant_unit_ident).skip(1))
-			}.map(|(variant_ident1,variant_ident2)|{
-				quote! { &Some(#ident::#variant_ident1) => {self.0 = Some(#ident::#variant_ident2); #ident::#variant_ident2}, }
+		let enum_attrs = attr::enum_attrs(&item.attrs)?;
+		let visibility = enum_attrs.iter.vis(&item.vis);
+		let derives = &enum_attrs.iter.derives;
+		let doc = format!("An iterator over the variants of `{}`",ident);
+
+		let indexed = indexed_variants(data)?;
+		let constructors = collect_results(indexed.iter().map(|variant| variant_constructor(ident,variant,&enum_attrs,std)))?;
+		let len = constructors.len();
+
+		let from_position = {
+			let match_arms = constructors.iter().enumerate().map(|(i,constructor)|{
+				let i = Literal::usize_unsuffixed(i);
+				quote! { #i => #constructor, }
 			});
-
-		let len_match_arms = data.iter().enumerate().map(|(i,variant)|{
-			let i = Lit::Int(i as u64,IntTy::Unsuffixed);
-			let variant_ident: &Ident = variant_unit_ident(variant);
-			quote! { &#ident::#variant_ident => #i, }
-		});
-
-		let count_match_arms = data.iter().enumerate().map(|(i,variant)|{
-			let i = Lit::Int(i as u64,IntTy::Unsuffixed);
-			let variant_ident: &Ident = variant_unit_ident(variant);
-			quote! { #ident::#variant_ident => #i, }
-		});
-
-		let variant_first_ident = &data.first().expect("`derive(EnumIter)` may only be applied to non-empty enums").ident;
-		//let variant_last_ident  = &data.last().expect("`derive(EnumIter)` may only be applied to non-empty enums").ident;
-
-		let struct_ident = {
-			let mut str = ident.as_ref().to_string();
-			str.push_str("Iter");
-			Ident::from(str)
+			quote!{
+				#[inline]
+				fn from_position #impl_generics (position: usize) -> #ident #ty_generics #where_clause{
+					match position{
+						#( #match_arms )*
+						_ => unreachable!()
+					}
+				}
+			}
 		};
 
+		let struct_ident = enum_attrs.iter.name(ident,"Iter");
+
+		//The remaining variants are the ones at the positions in the range `front..back`.
+		//The generic parameters of the enum are only used by the marker, which neither owns nor borrows any data of the enum.
+		let generics = &item.generics;
 		let struct_iter = quote!{
-			#visibility struct #struct_ident #ty_generics #where_clause (pub Option<#ident #ty_generics>);
+			#[doc = #doc]
+			#[derive( #( #derives ),* )]
+			#visibility struct #struct_ident #generics #where_clause{
+				front: usize,
+				back: usize,
+				marker: ::#std::marker::PhantomData<fn() -> #ident #ty_generics>,
+			}
 		};
 
 		let impl_default = quote!{
@@ -525,7 +952,7 @@ pub fn derive_EnumIter(input: TokenStream) -> TokenStream{//TODO: Consider rewri
 			#[allow(unused_attributes)]
 			impl #impl_generics ::#std::default::Default for #struct_ident #ty_generics #where_clause{
 				#[inline(always)]
-				fn default() -> Self{#struct_ident (None)}
+				fn default() -> Self{#struct_ident{front: 0,back: #len,marker: ::#std::marker::PhantomData}}
 			}
 		};
 
@@ -533,11 +960,13 @@ pub fn derive_EnumIter(input: TokenStream) -> TokenStream{//TODO: Consider rewri
 			let fn_next = quote!{
 				#[inline]
 				fn next(&mut self) -> Option<Self::Item>{
-					Some(match &self.0{
-						&None => {self.0 = Some(#ident::#variant_first_ident); #ident::#variant_first_ident},
-						#( #next_match_arms )*
-						_ => return None
-					})
+					#from_position
+					if self.front < self.back{
+						self.front+= 1;
+						Some(from_position(self.front - 1))
+					}else{
+						None
+					}
 				}
 			};
 
@@ -552,31 +981,24 @@ pub fn derive_EnumIter(input: TokenStream) -> TokenStream{//TODO: Consider rewri
 			let fn_count = quote!{
 				#[inline(always)]
 				fn count(self) -> usize{
-					self.0.map_or(#len,|variant|{
-						#len - 1 - match variant{
-							#( #count_match_arms )*
-						}
-					})
+					use ::#std::iter::ExactSizeIterator;
+					self.len()
 				}
 			};
 
-			let fn_last = if let Some(last_ident) = last.map(variant_unit_ident){quote!{
+			let fn_last = quote!{
 				#[inline(always)]
-				fn last(self) -> Option<Self::Item>{
-					Some(#ident::#last_ident)
-				}
-			}}else{quote!{
-				#[inline(always)]
-				fn last(self) -> Option<Self::Item>{
-					None
+				fn last(mut self) -> Option<Self::Item>{
+					use ::#std::iter::DoubleEndedIterator;
+					self.next_back()
 				}
-			}};
+			};
 
 			quote!{
 				#[automatically_derived]
 				#[allow(unused_attributes)]
 				impl #impl_generics ::#std::iter::Iterator for #struct_ident #ty_generics #where_clause{
-					type Item = #ident;
+					type Item = #ident #ty_generics;
 
 					#fn_next
 					#fn_size_hint
@@ -586,21 +1008,22 @@ pub fn derive_EnumIter(input: TokenStream) -> TokenStream{//TODO: Consider rewri
 			}
 		};
 
-		//TODO: May be an incorrect use of DoubleEndedIterator. Use Step instead
-		/*let impl_diter = quote!{
+		let impl_diter = quote!{
 			#[automatically_derived]
 			#[allow(unused_attributes)]
-			impl #impl_generics ::#std::iter::Iterator for ::#std::iter::Rev<#struct_ident> #ty_generics #where_clause{
+			impl #impl_generics ::#std::iter::DoubleEndedIterator for #struct_ident #ty_generics #where_clause{
 				#[inline]
 				fn next_back(&mut self) -> Option<Self::Item>{
-					Some(match &self.0{
-						&None => {self.0 = Some(#ident::#variant_last_ident); #ident::#variant_last_ident},
-						#( #prev_match_arms )*
-						_ => return None
-					})
+					#from_position
+					if self.front < self.back{
+						self.back-= 1;
+						Some(from_position(self.back))
+					}else{
+						None
+					}
 				}
 			}
-		};*/
+		};
 
 		let impl_exactiter = quote!{
 			#[automatically_derived]
@@ -608,11 +1031,7 @@ pub fn derive_EnumIter(input: TokenStream) -> TokenStream{//TODO: Consider rewri
 			impl #impl_generics ::#std::iter::ExactSizeIterator for #struct_ident #ty_generics #where_clause{
 				#[inline]
 				fn len(&self) -> usize{
-					self.0.as_ref().map_or(#len,|variant|{
-						#len - 1 - match variant{
-							#( #len_match_arms )*
-						}
-					})
+					self.back - self.front
 				}
 			}
 		};
@@ -621,21 +1040,43 @@ pub fn derive_EnumIter(input: TokenStream) -> TokenStream{//TODO: Consider rewri
 			#[automatically_derived]
 			#[allow(unused_attributes)]
 			impl #impl_generics ::enum_traits::Iterable for #ident #ty_generics #where_clause{
-				type Iter = #struct_ident;
-				#[inline(always)]fn variants() -> Self::Iter{#struct_ident(None)}
+				type Iter = #struct_ident #ty_generics;
+				#[inline(always)]fn variants() -> Self::Iter{::#std::default::Default::default()}
+			}
+		};
+
+		//The variants are constants when every variant is an unit variant that is not constructed by an expression
+		let only_unit_variants = collect_results(indexed.iter().map(|variant|
+			attr::variant_attrs(&variant.attrs).map(|variant_attrs| variant_attrs.construct.is_none() && matches!(variant.fields,Fields::Unit))
+		))?.into_iter().all(|unit| unit);
+		let impl_variants = if only_unit_variants{
+			let mut generics = item.generics.clone();
+			if !generics.params.is_empty(){
+				generics.make_where_clause().predicates.push(parse_quote!(#ident #ty_generics: 'static));
+			}
+			let (impl_generics,_,where_clause) = generics.split_for_impl();
+			quote!{
+				#[automatically_derived]
+				#[allow(unused_attributes)]
+				impl #impl_generics ::enum_traits::Variants for #ident #ty_generics #where_clause{
+					const VARIANTS: &'static [Self] = &[ #( #constructors ),* ];
+				}
 			}
+		}else{
+			quote!{}
 		};
 
-		quote!{
+		Ok(quote!{
 			#struct_iter
 			#impl_intoiter
+			#impl_variants
 			#impl_default
 			#impl_iter
-			//#impl_diter
+			#impl_diter
 			#impl_exactiter
-		}
+		})
 	}
-	derive_enum(input,gen_impl)
+	derive_enum(input,"EnumIter",gen_impl)
 }
 
 /// Implements `Iterator`.
@@ -644,40 +1085,39 @@ pub fn derive_EnumIter(input: TokenStream) -> TokenStream{//TODO: Consider rewri
 /// - The derived item is an enum
 /// - The enum variants is all unit variants
 #[proc_macro_derive(EnumIterator)]
-pub fn derive_EnumIterator(input: TokenStream) -> TokenStream{
-	fn variant_unit_ident(variant: &Variant) -> &Ident{
+pub fn derive_EnumIterator(input: proc_macro::TokenStream) -> proc_macro::TokenStream{
+	fn variant_unit_ident(variant: &Variant) -> syn::Result<&Ident>{
 		::variant_unit_ident(variant,"EnumIterator")
 	}
 
-	fn gen_impl(ident: &Ident,item: &MacroInput,data: &Vec<Variant>, std: Ident) -> Tokens{
+	fn gen_impl(ident: &Ident,item: &DeriveInput,data: &[Variant],std: &Ident) -> syn::Result<TokenStream>{
 		let (impl_generics,ty_generics,where_clause) = item.generics.split_for_impl();
 
-		let len = data.len();
-		let last = data.last();
+		let variant_idents = collect_results(data.iter().map(variant_unit_ident))?;
+		let len = variant_idents.len();
+		let last = variant_idents.last();
 
 		/*let prev_match_arms = {
-				let iter = data.iter().rev().map(variant_unit_ident);
-				iter.zip(data.iter().rev().map(variant_unit_ident).skip(1))
+				let iter = variant_idents.iter().rev();
+				iter.zip(variant_idents.iter().rev().skip(1))
 			}.map(|(variant_ident1,variant_ident2)|{
 				quote! { &mut #ident::#variant_ident1 => {*self = #ident::#variant_ident2; #ident::#variant_ident2}, }
 			});*/
 
 		let next_match_arms = {
-				let iter = data.iter().map(variant_unit_ident);
-				iter.zip(data.iter().map(variant_unit_ident).skip(1))
+				let iter = variant_idents.iter();
+				iter.zip(variant_idents.iter().skip(1))
 			}.map(|(variant_ident1,variant_ident2)|{
 				quote! { &mut #ident::#variant_ident1 => {*self = #ident::#variant_ident2; #ident::#variant_ident2}, }
 			});
 
-		let len_match_arms = data.iter().enumerate().map(|(i,variant)|{
-			let i = Lit::Int(i as u64,IntTy::Unsuffixed);
-			let variant_ident: &Ident = variant_unit_ident(variant);
+		let len_match_arms = variant_idents.iter().enumerate().map(|(i,variant_ident)|{
+			let i = Literal::usize_unsuffixed(i);
 			quote! { &#ident::#variant_ident => #i, }
 		});
 
-		let count_match_arms = data.iter().enumerate().map(|(i,variant)|{
-			let i = Lit::Int(i as u64,IntTy::Unsuffixed);
-			let variant_ident: &Ident = variant_unit_ident(variant);
+		let count_match_arms = variant_idents.iter().enumerate().map(|(i,variant_ident)|{
+			let i = Literal::usize_unsuffixed(i);
 			quote! { #ident::#variant_ident => #i, }
 		});
 
@@ -710,7 +1150,7 @@ pub fn derive_EnumIterator(input: TokenStream) -> TokenStream{
 				}
 			};
 
-			let fn_last = if let Some(last_ident) = last.map(variant_unit_ident){quote!{
+			let fn_last = if let Some(last_ident) = last{quote!{
 				#[inline(always)]
 				fn last(self) -> Option<Self::Item>{
 					Some(#ident::#last_ident)
@@ -763,91 +1203,421 @@ pub fn derive_EnumIterator(input: TokenStream) -> TokenStream{
 			}
 		};
 
-		quote!{
+		Ok(quote!{
 			#impl_iter
 			//#impl_diter
 			#impl_eiter
-		}
+		})
 	}
-	derive_enum(input,gen_impl)
+	derive_enum(input,"EnumIterator",gen_impl)
 }
 
-/// Implements `enum_traits::Discriminant`.
+/// Implements `enum_traits::Sequential`, stepping between the variants of an enum in the defined order.
 ///
 /// # Requirements
 /// - The derived item is an enum
+/// - The enum has at least one variant
 /// - The enum variants is all unit variants
-#[proc_macro_derive(EnumDiscriminant)]
-pub fn derive_EnumDiscriminant(input: TokenStream) -> TokenStream{
-	fn gen_impl(ident: &Ident,item: &MacroInput,data: &Vec<Variant>, std: Ident) -> Tokens{
-		let (impl_generics,ty_generics,where_clause) = item.generics.split_for_impl();
+/// - No variant is marked by `#[enum_traits(skip)]` or `#[enum_traits(skip(iter))]`, the same as in `derive(EnumToIndex)`.
+///   The steps are in the order of the indices, so `distance` between the first variant and a variant is its index.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
+/// # extern crate enum_traits;
+/// # use enum_traits::*;
+/// # fn main(){
+/// #[derive(Debug,Eq,PartialEq,EnumSequential)]
+/// enum Tab{General,Network,Advanced}
+///
+/// assert_eq!(Some(Tab::Network),Tab::General.succ());
+/// assert_eq!(None              ,Tab::General.pred());
+/// assert_eq!(Tab::General      ,Tab::Advanced.succ_wrapping());
+/// assert_eq!(Tab::Advanced     ,Tab::Advanced.succ_saturating());
+/// assert_eq!(Tab::Network      ,Tab::General.offset_wrapping(4));
+/// assert_eq!(2                 ,Tab::distance(&Tab::General,&Tab::Advanced));
+/// # }
+/// ```
+///
+/// A skipped variant is reported as an error at the variant:
+///
+/// ```compile_fail
+/// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
+/// # extern crate enum_traits;
+/// # fn main(){}
+/// #[derive(EnumSequential)]
+/// enum Tab{
+/// 	General,
+/// 	#[enum_traits(skip)]
+/// 	Hidden,
+/// 	Advanced,
+/// }
+/// ```
+#[proc_macro_derive(EnumSequential,attributes(enum_traits))]
+pub fn derive_EnumSequential(input: proc_macro::TokenStream) -> proc_macro::TokenStream{
+	fn variant_unit_ident(variant: &Variant) -> syn::Result<&Ident>{
+		::variant_unit_ident(variant,"EnumSequential")
+	}
 
-		fn variant_to_match_arm(ident: &Ident,variant: &Variant,only_unit_variants: bool) -> Option<Tokens>{
-			let variant_ident = &variant.ident;
+	fn gen_impl(ident: &Ident,item: &DeriveInput,data: &[Variant],std: &Ident) -> syn::Result<TokenStream>{
+		let (impl_generics,ty_generics,where_clause) = item.generics.split_for_impl();
 
-			//If an explicit discriminant exists
-			variant.discriminant.as_ref().map(|ref variant_discriminant|{
-				match variant.data{
-					VariantData::Unit => {
-						quote! { #variant_discriminant => #ident::#variant_ident, }
-					}
-					VariantData::Tuple(_)  |
-					VariantData::Struct(_) => {
-						//Tuple and struct variants cannot have explicit discriminants
-						unreachable!()
+		if data.is_empty(){
+			return Err(Error::new_spanned(ident,"`derive(EnumSequential)` may only be applied to non-empty enums"));
+		}
+		//The positions are the indices, as given by `derive(EnumToIndex)` and `derive(EnumFromIndex)`
+		check_no_skipped_variants(data,"EnumSequential")?;
+		let variant_idents = collect_results(indexed_variants(data)?.into_iter().map(variant_unit_ident))?;
+		let len = variant_idents.len() as isize;
+		let last = len - 1;
+
+		let position = {
+			let match_arms = variant_idents.iter().enumerate().map(|(i,variant_ident)|{
+				let i = Literal::usize_unsuffixed(i);
+				quote! { &#ident::#variant_ident => #i, }
+			});
+			quote!{
+				#[inline]
+				fn position #impl_generics (variant: &#ident #ty_generics) -> isize #where_clause{
+					match variant{
+						#( #match_arms )*
 					}
 				}
-			}).or_else(||{
-				match variant.data{
-					VariantData::Unit if only_unit_variants => Some({
-						quote! { n if n==#ident::#variant_ident as Self::Type => #ident::#variant_ident, }
-					}),
-					_ => None
-				}
-			})
+			}
 		};
-		let only_unit_variants = data.iter().all(|variant| match variant.data{VariantData::Unit => true , _ => false});
-		let match_arms1 = data.iter().filter_map(|variant| variant_to_match_arm(ident,variant,only_unit_variants));
-		let match_arms2 = data.iter().filter_map(|variant| variant_to_match_arm(ident,variant,only_unit_variants));
-		let ty = type_from_repr_attr(item.attrs.iter()).unwrap_or(Ident::from("usize"));
-
-		quote!{
-			#[automatically_derived]
-			#[allow(unused_attributes)]
-			impl #impl_generics ::enum_traits::Discriminant for #ident #ty_generics #where_clause{
-				type Type = #ty;
 
+		let from_position = {
+			let match_arms = variant_idents.iter().enumerate().map(|(i,variant_ident)|{
+				let i = Literal::usize_unsuffixed(i);
+				quote! { #i => #ident::#variant_ident, }
+			});
+			quote!{
 				#[inline]
-				fn from_discriminant(discriminant: <Self as Discriminant>::Type) -> Option<Self>{
-					Some(match discriminant{
-						#( #match_arms1 )*
+				fn from_position #impl_generics (position: isize) -> Option<#ident #ty_generics> #where_clause{
+					Some(match position{
+						#( #match_arms )*
 						_ => return None
 					})
 				}
-
+			}
+		};
+
+		Ok(quote!{
+			#[automatically_derived]
+			#[allow(unused_attributes)]
+			impl #impl_generics ::enum_traits::Sequential for #ident #ty_generics #where_clause{
+				fn offset(&self,n: isize) -> Option<Self>{
+					#position
+					#from_position
+					position(self).checked_add(n).and_then(from_position)
+				}
+
+				fn offset_wrapping(&self,n: isize) -> Self{
+					#position
+					#from_position
+					match from_position((position(self) + n.rem_euclid(#len)) % #len){
+						Some(variant) => variant,
+						None => unreachable!()
+					}
+				}
+
+				fn offset_saturating(&self,n: isize) -> Self{
+					#position
+					#from_position
+					match from_position(::#std::cmp::min(::#std::cmp::max(position(self).saturating_add(n),0),#last)){
+						Some(variant) => variant,
+						None => unreachable!()
+					}
+				}
+
 				#[inline]
-				unsafe fn from_discriminant_unchecked(discriminant: <Self as Discriminant>::Type) -> Self{
+				fn distance(from: &Self,to: &Self) -> isize{
+					#position
+					position(to) - position(from)
+				}
+			}
+		})
+	}
+	derive_enum(input,"EnumSequential",gen_impl)
+}
+
+/// Implements `enum_traits::Discriminant`.
+///
+/// The type of the discriminant is the primitive type given by a `repr` attribute.
+/// Otherwise, it is the smallest integer type containing every discriminant (signed when there are negative discriminants), or `isize` when a discriminant is not only integer literals and arithmetic (e.g. a constant).
+/// Variants without an explicit discriminant have the discriminant of the previous variant plus one, starting from zero, as assigned by the compiler.
+/// Only unit variants can be constructed by `from_discriminant`.
+///
+/// `from_discriminant_unchecked` is a transmute when the enum has a primitive representation and only unit variants.
+/// Otherwise it is a match where a discriminant not belonging to a variant is unreachable.
+/// In both cases, the discriminant is checked by a debug assertion.
+///
+/// # Requirements
+/// - The derived item is an enum
+///
+/// # Examples
+///
+/// ```rust
+/// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
+/// # extern crate enum_traits;
+/// # use enum_traits::*;
+/// # fn main(){
+/// #[derive(Debug,PartialEq,EnumDiscriminant)]
+/// #[repr(u8)]
+/// enum Message {
+/// 	Ping,
+/// 	Data(u32) = 0x10,
+/// 	Text{len: u16},
+/// 	Close = 0xFF,
+/// }
+/// assert_eq!(Message::Ping.discriminant(), 0x00);
+/// assert_eq!(Message::Data(7).discriminant(), 0x10);
+/// assert_eq!(Message::Text{len: 0}.discriminant(), 0x11);
+/// assert_eq!(Message::Close.discriminant(), 0xFF);
+///
+/// assert_eq!(Message::from_discriminant(0xFF), Some(Message::Close));
+/// assert_eq!(Message::from_discriminant(0x10), None);
+/// # }
+/// ```
+///
+/// ```rust
+/// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
+/// # extern crate enum_traits;
+/// # use enum_traits::*;
+/// # fn main(){
+/// #[derive(Debug,PartialEq,EnumDiscriminant)]
+/// enum Level {
+/// 	Debug = -1,
+/// 	Info,
+/// 	Warning = 1 << 4,
+/// }
+/// let level: i8 = Level::Warning.discriminant();
+/// assert_eq!(level, 16);
+/// assert_eq!(Level::from_discriminant(-1), Some(Level::Debug));
+/// assert_eq!(Level::from_discriminant(0), Some(Level::Info));
+/// # }
+/// ```
+#[proc_macro_derive(EnumDiscriminant,attributes(enum_traits))]
+pub fn derive_EnumDiscriminant(input: proc_macro::TokenStream) -> proc_macro::TokenStream{
+	fn gen_impl(ident: &Ident,item: &DeriveInput,data: &[Variant],std: &Ident) -> syn::Result<TokenStream>{
+		let (impl_generics,ty_generics,where_clause) = item.generics.split_for_impl();
+		let only_unit_variants = data.iter().all(|variant| matches!(variant.fields,Fields::Unit));
+		let values = discriminant_values(data);
+		let ty = type_from_repr_attr(item.attrs.iter()).unwrap_or_else(|| minimum_type_from_discriminants(&values));
+
+		//The discriminant of every variant as an expression of the type `ty`, and whether the expression is usable as a pattern.
+		//Evaluated discriminants are literals, and the others are expressions only usable in guards.
+		let discriminants: Vec<(TokenStream,bool)> = {
+			let mut discriminants = Vec::with_capacity(data.len());
+			let mut previous_explicit = None;
+			let mut offset = 0u64;
+			for (variant,value) in data.iter().zip(values.iter()){
+				let variant_ident = &variant.ident;
+				if let Some((_,ref variant_discriminant)) = variant.discriminant{
+					previous_explicit = Some(variant_discriminant);
+					offset = 0;
+				}
+				discriminants.push(match (*value,previous_explicit){
+					(Some(value),_) => (int_lit(value),true),
+					(None,_) if only_unit_variants => (quote! { #ident::#variant_ident as #ty },false),
+					//Casting is not allowed for enums with fields, so the discriminant is computed in the same way as the compiler does
+					(None,Some(previous_discriminant)) => {
+						let offset_lit = Literal::u64_unsuffixed(offset);
+						(quote! { (#previous_discriminant) + #offset_lit },false)
+					}
+					//Implicit discriminants without a previous explicit discriminant are always evaluated
+					(None,None) => unreachable!()
+				});
+				offset+= 1;
+			}
+			discriminants
+		};
+
+		let from_match_arms: Vec<TokenStream> = data.iter().zip(discriminants.iter()).filter_map(|(variant,&(ref discriminant,is_pattern))|{
+			let variant_ident = &variant.ident;
+			match variant.fields{
+				Fields::Unit if is_pattern => Some(quote! { #discriminant => #ident::#variant_ident, }),
+				Fields::Unit => Some(quote! { n if n==#discriminant => #ident::#variant_ident, }),
+				_ => None
+			}
+		}).collect();
+		let from_match_arms1 = &from_match_arms;
+
+		//Every discriminant of an enum with only unit variants and a primitive representation is valid as the representation of a variant
+		let fn_from_discriminant_unchecked_body = match type_from_repr_attr(item.attrs.iter()){
+			Some(ref repr) if only_unit_variants => quote!{
+				::#std::mem::transmute::<#repr,Self>(discriminant)
+			},
+			_ => {
+				let from_match_arms2 = &from_match_arms;
+				quote!{
 					match discriminant{
-						#( #match_arms2 )*
-						_ => ::#std::mem::uninitialized()
+						#( #from_match_arms2 )*
+						_ => ::#std::hint::unreachable_unchecked()
 					}
 				}
 			}
-		}
+		};
+
+		let match_arms = data.iter().zip(discriminants.iter()).map(|(variant,(discriminant,_))|{
+			let variant_ident = &variant.ident;
+			let discriminant = if only_unit_variants{quote! { #ident::#variant_ident as #ty }}else{discriminant.clone()};
+			match variant.fields{
+				Fields::Unit       => quote! { #ident::#variant_ident     => #discriminant, },
+				Fields::Unnamed(_) => quote! { #ident::#variant_ident(..) => #discriminant, },
+				Fields::Named(_)   => quote! { #ident::#variant_ident{..} => #discriminant, },
+			}
+		});
+
+		Ok(quote!{
+			#[automatically_derived]
+			#[allow(unused_attributes)]
+			impl #impl_generics ::enum_traits::Discriminant for #ident #ty_generics #where_clause{
+				type Type = #ty;
+
+				#[inline]
+				fn discriminant(&self) -> <Self as ::enum_traits::Discriminant>::Type{
+					match *self{
+						#( #match_arms )*
+					}
+				}
+
+				#[inline]
+				fn from_discriminant(discriminant: <Self as ::enum_traits::Discriminant>::Type) -> Option<Self>{
+					Some(match discriminant{
+						#( #from_match_arms1 )*
+						_ => return None
+					})
+				}
+
+				#[inline]
+				unsafe fn from_discriminant_unchecked(discriminant: <Self as ::enum_traits::Discriminant>::Type) -> Self{
+					debug_assert!(<Self as ::enum_traits::Discriminant>::from_discriminant(discriminant).is_some(),"`Discriminant::from_discriminant_unchecked` was called with a discriminant not belonging to a variant");
+					#fn_from_discriminant_unchecked_body
+				}
+			}
+		})
 	}
-	derive_enum(input,gen_impl)
+	derive_enum(input,"EnumDiscriminant",gen_impl)
+}
+
+/// Implements conversions between the enum and the primitive type of its representation (`#[repr(u8)]`): `TryFrom<u8>` for the enum, `From<Enum>` for `u8` and `PartialEq<u8>` for the enum.
+/// The conversions use the discriminants of the variants.
+///
+/// The error of `TryFrom` is `enum_traits::TryFromReprError`, containing the rejected value.
+///
+/// # Requirements
+/// - The derived item is an enum
+/// - The enum has a primitive integer representation (`#[repr(u8)]`, `#[repr(i32)]`, ...)
+/// - Every variant is a unit variant
+///
+/// # Examples
+///
+/// ```rust
+/// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
+/// # extern crate enum_traits;
+/// # use std::convert::TryFrom;
+/// # use enum_traits::TryFromReprError;
+/// # fn main(){
+/// #[derive(Debug,PartialEq,EnumRepr)]
+/// #[repr(u8)]
+/// enum Opcode {
+/// 	Nop = 0x00,
+/// 	Load = 0x10,
+/// 	Store,
+/// }
+///
+/// fn decode(bytes: &[u8]) -> Result<Vec<Opcode>,TryFromReprError<u8>>{
+/// 	bytes.iter().map(|&byte| Opcode::try_from(byte)).collect()
+/// }
+///
+/// assert_eq!(Opcode::try_from(0x11), Ok(Opcode::Store));
+/// assert_eq!(Opcode::try_from(0x12).unwrap_err().value(), 0x12);
+/// assert_eq!(u8::from(Opcode::Load), 0x10);
+/// assert!(Opcode::Load == 0x10);
+/// assert_eq!(decode(&[0x00,0x10]), Ok(vec![Opcode::Nop,Opcode::Load]));
+/// assert!(decode(&[0x00,0xFF]).is_err());
+/// # }
+/// ```
+#[proc_macro_derive(EnumRepr,attributes(enum_traits))]
+pub fn derive_EnumRepr(input: proc_macro::TokenStream) -> proc_macro::TokenStream{
+	fn gen_impl(ident: &Ident,item: &DeriveInput,data: &[Variant],std: &Ident) -> syn::Result<TokenStream>{
+		let (impl_generics,ty_generics,where_clause) = item.generics.split_for_impl();
+		let repr = type_from_repr_attr(item.attrs.iter()).ok_or_else(|| Error::new_spanned(ident,"`derive(EnumRepr)` requires a primitive integer representation, for example `#[repr(u8)]`"))?;
+		let variant_idents = collect_results(data.iter().map(|variant| variant_unit_ident(variant,"EnumRepr")))?;
+		let type_name = ident.to_string();
+
+		//Evaluated discriminants are patterns, and the others are compared in guards
+		let from_match_arms = variant_idents.iter().zip(discriminant_values(data)).map(|(variant_ident,value)| match value{
+			Some(value) => {
+				let value = int_lit(value);
+				quote! { #value => #ident::#variant_ident, }
+			}
+			None => quote! { value if value == #ident::#variant_ident as #repr => #ident::#variant_ident, }
+		});
+		let to_match_arms = variant_idents.iter().map(|variant_ident| quote! { #ident::#variant_ident => #ident::#variant_ident as #repr, });
+
+		Ok(quote!{
+			#[automatically_derived]
+			#[allow(unused_attributes)]
+			impl #impl_generics ::#std::convert::TryFrom<#repr> for #ident #ty_generics #where_clause{
+				type Error = ::enum_traits::TryFromReprError<#repr>;
+
+				#[inline]
+				fn try_from(value: #repr) -> ::#std::result::Result<Self,Self::Error>{
+					Ok(match value{
+						#( #from_match_arms )*
+						_ => return Err(::enum_traits::TryFromReprError::new(value,#type_name))
+					})
+				}
+			}
+
+			#[automatically_derived]
+			#[allow(unused_attributes)]
+			impl #impl_generics ::#std::convert::From<#ident #ty_generics> for #repr #where_clause{
+				#[inline(always)]
+				fn from(value: #ident #ty_generics) -> Self{
+					value as #repr
+				}
+			}
+
+			#[automatically_derived]
+			#[allow(unused_attributes)]
+			impl #impl_generics ::#std::cmp::PartialEq<#repr> for #ident #ty_generics #where_clause{
+				#[inline]
+				fn eq(&self,other: &#repr) -> bool{
+					(match *self{
+						#( #to_match_arms )*
+					}) == *other
+				}
+			}
+		})
+	}
+	derive_enum(input,"EnumRepr",gen_impl)
 }
 
 /// Implements `enum_traits::EnumVariantName`, giving the name of the variants of an enum as a string.
+/// Also implements `enum_traits::VariantNames`, the names of every variant in the defined order.
 ///
 /// # Requirements
 /// - The derived item is an enum
 ///
+/// # Attributes
+/// - `#[enum_traits(rename_all = "...")]` on the enum: Converts the variant names to a case convention.
+///   One of `lowercase`, `UPPERCASE`, `PascalCase`, `camelCase`, `snake_case`, `SCREAMING_SNAKE_CASE`, `kebab-case` and `SCREAMING-KEBAB-CASE`.
+/// - `#[enum_traits(rename = "...")]` on a variant: The name of the variant, overriding `rename_all`.
+///
 /// # Examples
 ///
 /// ```rust
-/// # #![feature(associated_consts)]
 /// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
 /// # extern crate enum_traits;
 /// # use enum_traits::*;
 /// # fn main(){
@@ -862,29 +1632,50 @@ pub fn derive_EnumDiscriminant(input: TokenStream) -> TokenStream{
 /// assert_eq!(Enum::Robot{speed: 0.0}.variant_name(), "Robot");
 /// # }
 /// ```
-#[proc_macro_derive(EnumVariantName)]
-pub fn derive_EnumVariantName(input: TokenStream) -> TokenStream {
-	fn gen_impl(ident: &Ident, item: &MacroInput, data: &Vec<Variant>, _: Ident) -> Tokens {
+///
+/// ```rust
+/// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
+/// # extern crate enum_traits;
+/// # use enum_traits::*;
+/// # fn main(){
+/// #[derive(EnumVariantName)]
+/// #[enum_traits(rename_all = "kebab-case")]
+/// enum Enum {
+/// 	GuideDog,
+/// 	#[enum_traits(rename = "kitten")]
+/// 	Cat(i32),
+/// 	HTTPRobot{speed: f32},
+/// }
+/// assert_eq!(Enum::GuideDog.variant_name(), "guide-dog");
+/// assert_eq!(Enum::Cat(0).variant_name(), "kitten");
+/// assert_eq!(Enum::HTTPRobot{speed: 0.0}.variant_name(), "http-robot");
+/// # }
+/// ```
+#[proc_macro_derive(EnumVariantName,attributes(enum_traits))]
+pub fn derive_EnumVariantName(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	fn gen_impl(ident: &Ident,item: &DeriveInput,data: &[Variant],_: &Ident) -> syn::Result<TokenStream>{
 		let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
+		let enum_attrs = attr::enum_attrs(&item.attrs)?;
+		let variant_names = collect_results(data.iter().map(|variant| attr::variant_attrs(&variant.attrs).map(|attrs| attrs.name(&enum_attrs,&variant.ident))))?;
 
-		let match_arms = data.iter().map(|variant| {
+		let match_arms = data.iter().zip(variant_names.iter()).map(|(variant,variant_str)| {
 			let variant_ident = &variant.ident;
-			let variant_str = variant.ident.as_ref();
 
-			match variant.data {
-				VariantData::Unit => {
+			match variant.fields {
+				Fields::Unit => {
 					quote! { &#ident::#variant_ident => #variant_str, }
 				}
-				VariantData::Tuple(_) => {
+				Fields::Unnamed(_) => {
 					quote! { &#ident::#variant_ident(..) => #variant_str, }
 				}
-				VariantData::Struct(_) => {
+				Fields::Named(_) => {
 					quote! { &#ident::#variant_ident{..} => #variant_str, }
 				}
 			}
 		});
 
-		quote!{
+		Ok(quote!{
 			#[automatically_derived]
 			#[allow(unused_attributes)]
 			impl #impl_generics ::enum_traits::VariantName for #ident #ty_generics #where_clause{
@@ -895,79 +1686,577 @@ pub fn derive_EnumVariantName(input: TokenStream) -> TokenStream {
 					}
 				}
 			}
-		}
+
+			#[automatically_derived]
+			#[allow(unused_attributes)]
+			impl #impl_generics ::enum_traits::VariantNames for #ident #ty_generics #where_clause{
+				const NAMES: &'static [&'static str] = &[ #( #variant_names ),* ];
+			}
+		})
 	}
-	derive_enum(input, gen_impl)
+	derive_enum(input,"EnumVariantName",gen_impl)
 }
 
-#[proc_macro_derive(EnumFromVariantName)]
-pub fn derive_EnumFromVariantName(input: TokenStream) -> TokenStream {//TODO: Consider not using FromStr, instead an own trait
-	fn gen_impl(ident: &Ident, item: &MacroInput, data: &Vec<Variant>, std: Ident) -> Tokens {
-		let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
+/// Implements `Display`, formatting every variant as its name or by a format string.
+///
+/// # Requirements
+/// - The derived item is an enum
+/// - Every argument in a format string refers to a field of the variant
+/// - Every field referred to implements the formatting trait of its format spec (`Display` for `{0}`, `Debug` for `{0:?}`, ...)
+///
+/// # Attributes
+/// - `#[enum_traits(display = "...")]` on a variant: The format string of the variant, with the same syntax as in `format!`.
+///   The fields are referred to by their indices in tuple variants (`{0}`) and by their names in struct variants (`{name}`).
+/// - `#[enum_traits(rename_all = "...")]` on the enum and `#[enum_traits(rename = "...")]` on a variant: The names of the variants without format strings, the same as in `derive(EnumVariantName)`.
+///
+/// Variants without format strings are formatted as their names, respecting the width, fill and alignment of the formatter.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
+/// # fn main(){
+/// #[derive(EnumDisplay)]
+/// #[enum_traits(rename_all = "lowercase")]
+/// enum Animal {
+/// 	Dog,
+/// 	#[enum_traits(display = "cat (age {0})")]
+/// 	Cat(u8),
+/// 	#[enum_traits(display = "{speed} m/s ({name:?})")]
+/// 	Robot{name: &'static str,speed: f32},
+/// 	Fish(u8),
+/// }
+/// assert_eq!(Animal::Dog.to_string(), "dog");
+/// assert_eq!(format!("[{:>5}]",Animal::Dog), "[  dog]");
+/// assert_eq!(Animal::Cat(3).to_string(), "cat (age 3)");
+/// assert_eq!(Animal::Robot{name: "R2",speed: 1.5}.to_string(), "1.5 m/s (\"R2\")");
+/// assert_eq!(Animal::Fish(1).to_string(), "fish");
+/// # }
+/// ```
+///
+/// Referring to a field that the variant does not have is rejected:
+///
+/// ```compile_fail
+/// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
+/// # fn main(){}
+/// #[derive(EnumDisplay)]
+/// enum Animal {
+/// 	#[enum_traits(display = "cat (age {age})")]
+/// 	Cat(u8),
+/// }
+/// ```
+#[proc_macro_derive(EnumDisplay,attributes(enum_traits))]
+pub fn derive_EnumDisplay(input: proc_macro::TokenStream) -> proc_macro::TokenStream{
+	fn gen_impl(ident: &Ident,item: &DeriveInput,data: &[Variant],std: &Ident) -> syn::Result<TokenStream>{
+		let (impl_generics,ty_generics,where_clause) = item.generics.split_for_impl();
+		let enum_attrs = attr::enum_attrs(&item.attrs)?;
 
-		let match_arms = data.iter().filter_map(|variant| {
+		let match_arms = collect_results(data.iter().map(|variant|{
 			let variant_ident = &variant.ident;
-			let variant_str = variant.ident.as_ref();
+			let variant_attrs = attr::variant_attrs(&variant.attrs)?;
+			let template = match variant_attrs.display{
+				Some(template) => template,
+				None => {
+					let name = variant_attrs.name(&enum_attrs,variant_ident);
+					return Ok(match variant.fields{
+						Fields::Unit       => quote! { #ident::#variant_ident     => f.pad(#name), },
+						Fields::Unnamed(_) => quote! { #ident::#variant_ident(..) => f.pad(#name), },
+						Fields::Named(_)   => quote! { #ident::#variant_ident{..} => f.pad(#name), },
+					});
+				}
+			};
 
-			if let VariantData::Unit = variant.data{
-				Some(quote! { #variant_str => #ident::#variant_ident, })
-			}else{
-				None
+			//The fields as referred to in the format string.
+			//The arguments of `format_args!` are prefixed by `_`, making them valid even for indices and keywords (`r#type`).
+			let keys: Vec<String> = match variant.fields{
+				Fields::Unit => Vec::new(),
+				Fields::Unnamed(ref fields) => (0..fields.unnamed.len()).map(|i| i.to_string()).collect(),
+				Fields::Named(ref fields) => fields.named.iter().filter_map(|field| field.ident.as_ref()).map(|field| field.unraw().to_string()).collect(),
+			};
+			let args: Vec<Ident> = keys.iter().map(|key| format_ident!("_{}",key)).collect();
+			let mut used = vec![false; keys.len()];
+			let rewritten = display::rewrite(&template.value(),|arg| match keys.iter().position(|key| key == arg){
+				Some(i) => {
+					used[i] = true;
+					Ok(args[i].to_string())
+				}
+				None => Err(format!("the variant has no field `{}`",arg)),
+			}).map_err(|message| Error::new_spanned(&template,format!("`derive(EnumDisplay)` cannot format the variant `{}`: {}",variant_ident,message)))?;
+			let template = LitStr::new(&rewritten,template.span());
+
+			let bindings: Vec<Ident> = keys.iter().map(|key| format_ident!("field_{}",key)).collect();
+			let used_args     = args.iter().zip(used.iter()).filter(|&(_,&used)| used).map(|(arg,_)| arg);
+			let used_bindings = bindings.iter().zip(used.iter()).filter(|&(_,&used)| used).map(|(binding,_)| binding);
+			let format = quote! { f.write_fmt(format_args!(#template #(,#used_args = #used_bindings)*)) };
+
+			Ok(match variant.fields{
+				Fields::Unit => quote! { #ident::#variant_ident => #format, },
+				Fields::Unnamed(_) => {
+					let patterns = bindings.iter().zip(used.iter()).map(|(binding,&used)| if used{quote! { ref #binding }}else{quote! { _ }});
+					quote! { #ident::#variant_ident( #( #patterns ),* ) => #format, }
+				}
+				Fields::Named(ref fields) => {
+					let patterns = fields.named.iter().zip(bindings.iter()).zip(used.iter()).filter(|&(_,&used)| used).map(|((field,binding),_)|{
+						let field_ident = &field.ident;
+						quote! { #field_ident: ref #binding }
+					});
+					quote! { #ident::#variant_ident{ #( #patterns, )* .. } => #format, }
+				}
+			})
+		}))?;
+
+		Ok(quote!{
+			#[automatically_derived]
+			#[allow(unused_attributes)]
+			impl #impl_generics ::#std::fmt::Display for #ident #ty_generics #where_clause{
+				fn fmt(&self,f: &mut ::#std::fmt::Formatter) -> ::#std::fmt::Result{
+					match *self{
+						#( #match_arms )*
+					}
+				}
+			}
+		})
+	}
+	derive_enum(input,"EnumDisplay",gen_impl)
+}
+
+/// Implements `enum_traits::VariantProps`, the string properties of every variant.
+///
+/// # Requirements
+/// - The derived item is an enum
+/// - No property is given more than once on the same variant
+///
+/// # Attributes
+/// - `#[enum_traits(prop(key = "value",...))]` on a variant: The properties of the variant. May be repeated.
+///   Variants without properties have no properties.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
+/// # extern crate enum_traits;
+/// # use enum_traits::*;
+/// # fn main(){
+/// #[derive(EnumVariantProps)]
+/// enum Status {
+/// 	#[enum_traits(prop(class = "status-ok",label = "OK"))]
+/// 	Ok,
+/// 	#[enum_traits(prop(class = "status-error"),prop(icon = "cross"))]
+/// 	Error(u16),
+/// 	Unknown,
+/// }
+/// assert_eq!(Status::Ok.get_prop("label"), Some("OK"));
+/// assert_eq!(Status::Error(404).get_prop("icon"), Some("cross"));
+/// assert_eq!(Status::Error(404).get_prop("label"), None);
+/// assert_eq!(Status::Ok.props(), &[("class","status-ok"),("label","OK")]);
+/// assert!(Status::Unknown.props().is_empty());
+/// # }
+/// ```
+#[proc_macro_derive(EnumVariantProps,attributes(enum_traits))]
+pub fn derive_EnumVariantProps(input: proc_macro::TokenStream) -> proc_macro::TokenStream{
+	fn gen_impl(ident: &Ident,item: &DeriveInput,data: &[Variant],_: &Ident) -> syn::Result<TokenStream>{
+		let (impl_generics,ty_generics,where_clause) = item.generics.split_for_impl();
+		let variants_attrs = collect_results(data.iter().map(|variant| attr::variant_attrs(&variant.attrs)))?;
+
+		let match_arms = data.iter().zip(variants_attrs.iter()).map(|(variant,variant_attrs)|{
+			let variant_ident = &variant.ident;
+			let keys   = variant_attrs.props.iter().map(|(key,_)| key);
+			let values = variant_attrs.props.iter().map(|(_,value)| value);
+			let props = quote! { &[ #( (#keys,#values) ),* ] };
+
+			match variant.fields{
+				Fields::Unit       => quote! { #ident::#variant_ident     => #props, },
+				Fields::Unnamed(_) => quote! { #ident::#variant_ident(..) => #props, },
+				Fields::Named(_)   => quote! { #ident::#variant_ident{..} => #props, },
 			}
 		});
 
-		quote!{
+		Ok(quote!{
 			#[automatically_derived]
 			#[allow(unused_attributes)]
-			impl #impl_generics ::#std::str::FromStr for #ident #ty_generics #where_clause{
-				type Err = ();
+			impl #impl_generics ::enum_traits::VariantProps for #ident #ty_generics #where_clause{
+				#[inline]
+				fn props(&self) -> &'static [(&'static str,&'static str)]{
+					match *self{
+						#( #match_arms )*
+					}
+				}
+			}
+		})
+	}
+	derive_enum(input,"EnumVariantProps",gen_impl)
+}
 
-				fn from_str(str: &str) -> ::#std::result::Result<Self,Self::Err>{
-					Ok(match str{
+/// Implements functions returning values associated with every variant, and functions constructing a variant from its unique associated value.
+///
+/// For every associated value `name: Type`, the function `fn name(&self) -> Type` is implemented.
+/// For every unique associated value, the function `fn from_name(value: Type) -> Option<Self>` is also implemented.
+///
+/// # Requirements
+/// - The derived item is an enum
+/// - Every variant has a value for every associated value
+/// - No two variants have the same unique associated value.
+///   Integer expressions are compared by their values and other expressions are compared as written.
+/// - Every variant is constructible when there are unique associated values (see `derive(EnumFromIndex)`)
+///
+/// # Attributes
+/// - `#[enum_traits(assoc(name: Type,...))]` on the enum: The names and types of the associated values.
+///   `unique` before a name (`assoc(unique name: Type)`) makes the value unique.
+/// - `#[enum_traits(name = value,...)]` on a variant: The associated value `name` of the variant, where `value` is an expression of the type of the value.
+/// - `#[enum_traits(default_fields)]` on the enum and `#[enum_traits(construct = "...")]` on a variant: How variants with fields are constructed, the same as in `derive(EnumFromIndex)`.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
+/// # fn main(){
+/// #[derive(Debug,PartialEq,EnumAssoc)]
+/// #[enum_traits(assoc(unique code: u16,symbol: char,weight: f32))]
+/// enum Status {
+/// 	#[enum_traits(code = 200,symbol = 'O',weight = 1.0)]
+/// 	Ok,
+/// 	#[enum_traits(code = 404,symbol = 'N',weight = 0.5)]
+/// 	NotFound,
+/// 	#[enum_traits(code = 500,symbol = 'E',weight = 0.0)]
+/// 	Error,
+/// }
+/// assert_eq!(Status::NotFound.code(), 404);
+/// assert_eq!(Status::Error.symbol(), 'E');
+/// assert_eq!(Status::Ok.weight(), 1.0);
+/// assert_eq!(Status::from_code(404), Some(Status::NotFound));
+/// assert_eq!(Status::from_code(418), None);
+/// # }
+/// ```
+///
+/// Two variants with the same unique value are rejected:
+///
+/// ```compile_fail
+/// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
+/// # fn main(){}
+/// #[derive(EnumAssoc)]
+/// #[enum_traits(assoc(unique code: u16))]
+/// enum Status {
+/// 	#[enum_traits(code = 0x10)]
+/// 	A,
+/// 	#[enum_traits(code = 16)]
+/// 	B,
+/// }
+/// ```
+#[proc_macro_derive(EnumAssoc,attributes(enum_traits))]
+pub fn derive_EnumAssoc(input: proc_macro::TokenStream) -> proc_macro::TokenStream{
+	fn gen_impl(ident: &Ident,item: &DeriveInput,data: &[Variant],std: &Ident) -> syn::Result<TokenStream>{
+		let (impl_generics,ty_generics,where_clause) = item.generics.split_for_impl();
+		let enum_attrs = attr::enum_attrs(&item.attrs)?;
+		let variants_attrs = collect_results(data.iter().map(|variant| attr::variant_attrs(&variant.attrs)))?;
+
+		if enum_attrs.assoc.is_empty(){
+			return Err(Error::new_spanned(ident,"`derive(EnumAssoc)` requires the associated values to be declared by `#[enum_traits(assoc(name: Type,...))]` on the enum"));
+		}
+
+		//The value of every associated value of every variant, in the order of the declarations
+		let values: Vec<Vec<&Expr>> = collect_results(enum_attrs.assoc.iter().map(|field|{
+			collect_results(data.iter().zip(variants_attrs.iter()).map(|(variant,variant_attrs)|{
+				match variant_attrs.assoc_values.iter().find(|&(name,_)| *name == field.name){
+					Some((_,value)) => Ok(value),
+					None => Err(Error::new_spanned(variant,format!("`derive(EnumAssoc)`: The variant `{}` has no value for `{}`. Give it by `#[enum_traits({} = ...)]`",variant.ident,field.name,field.name))),
+				}
+			}))
+		}))?;
+
+		let patterns: Vec<TokenStream> = data.iter().map(|variant|{
+			let variant_ident = &variant.ident;
+			match variant.fields{
+				Fields::Unit       => quote! { #ident::#variant_ident     },
+				Fields::Unnamed(_) => quote! { #ident::#variant_ident(..) },
+				Fields::Named(_)   => quote! { #ident::#variant_ident{..} },
+			}
+		}).collect();
+
+		let fns = enum_attrs.assoc.iter().zip(values.iter()).map(|(field,values)|{
+			let name = &field.name;
+			let ty = &field.ty;
+			let patterns = &patterns;
+			quote!{
+				#[inline]
+				#[allow(dead_code)]
+				pub fn #name(&self) -> #ty{
+					match *self{
+						#( #patterns => #values, )*
+					}
+				}
+			}
+		});
+
+		let unique_fields: Vec<(&attr::AssocField,&Vec<&Expr>)> = enum_attrs.assoc.iter().zip(values.iter()).filter(|&(field,_)| field.unique).collect();
+		let from_fns = if unique_fields.is_empty(){
+			Vec::new()
+		}else{
+			let constructors = collect_results(data.iter().map(|variant| variant_constructor(ident,variant,&enum_attrs,std,"EnumAssoc")))?;
+			collect_results(unique_fields.into_iter().map(|(field,values)|{
+				//Integer expressions are compared by their values, and other expressions by their tokens
+				let mut keys: Vec<(String,&Ident)> = Vec::with_capacity(values.len());
+				let mut error: Option<Error> = None;
+				for (variant,value) in data.iter().zip(values.iter()){
+					let key = match eval_const_expr(value){
+						Some(value) => value.to_string(),
+						None        => quote!(#value).to_string(),
+					};
+					if let Some(&(_,other_ident)) = keys.iter().find(|&(other_key,_)| *other_key == key){
+						let e = Error::new_spanned(value,format!("`derive(EnumAssoc)`: The unique value `{}` of `{}` of the variant `{}` is already used by the variant `{}`",quote!(#value),field.name,variant.ident,other_ident));
+						match error{Some(ref mut error) => error.combine(e) , None => error = Some(e)}
+					}
+					keys.push((key,&variant.ident));
+				}
+				if let Some(error) = error{
+					return Err(error);
+				}
+
+				let name = &field.name;
+				let ty = &field.ty;
+				let fn_ident = format_ident!("from_{}",name.unraw());
+				Ok(quote!{
+					#[inline]
+					#[allow(dead_code)]
+					pub fn #fn_ident(value: #ty) -> Option<Self>{
+						#({
+							let variant_value: #ty = #values;
+							if value == variant_value{
+								return Some(#constructors);
+							}
+						})*
+						None
+					}
+				})
+			}))?
+		};
+
+		Ok(quote!{
+			#[automatically_derived]
+			#[allow(unused_attributes)]
+			impl #impl_generics #ident #ty_generics #where_clause{
+				#( #fns )*
+				#( #from_fns )*
+			}
+		})
+	}
+	derive_enum(input,"EnumAssoc",gen_impl)
+}
+
+/// Implements `enum_traits::FromVariantName` and `FromStr`, constructing unit variants of an enum from their names.
+/// Variants with fields are not constructed unless they are constructible by `default_fields` or `construct` (see below).
+///
+/// # Requirements
+/// - The derived item is an enum
+/// - No two variants accept the same name
+///
+/// # Attributes
+/// - `#[enum_traits(rename_all = "...")]` on the enum and `#[enum_traits(rename = "...")]` on a variant: The names of the variants, the same as in `derive(EnumVariantName)`.
+///   The names of the variant identifiers are not accepted when renamed.
+/// - `#[enum_traits(alias = "...")]` on a variant: An additional name accepted for the variant. May be repeated.
+/// - `#[enum_traits(skip)]` or `#[enum_traits(skip(parse))]` on a variant: No name is accepted for the variant.
+/// - `#[enum_traits(parse(case_insensitive))]` on the enum: Names are matched case insensitively (Unicode lowercase folding).
+/// - `#[enum_traits(default_fields)]` on the enum: Variants with fields are constructed using `Default::default()` for every field.
+/// - `#[enum_traits(construct = "...")]` on a variant: The expression constructing the variant, overriding `default_fields`.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
+/// # extern crate enum_traits;
+/// # use enum_traits::*;
+/// # fn main(){
+/// #[derive(Debug,PartialEq,EnumFromVariantName)]
+/// enum Enum {
+/// 	Dog,
+/// 	Cat,
+/// 	Robot{speed: f32},
+/// }
+/// assert_eq!(Enum::from_variant_name("Dog"), Ok(Enum::Dog));
+/// assert_eq!("Cat".parse(), Ok(Enum::Cat));
+///
+/// let error = Enum::from_variant_name("Robot").unwrap_err();
+/// assert_eq!(error.input(), "Robot");
+/// assert_eq!(error.type_name(), "Enum");
+/// assert_eq!(error.to_string(), "no variant named \"Robot\" in `Enum`");
+/// # }
+/// ```
+///
+/// ```rust
+/// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
+/// # extern crate enum_traits;
+/// # use enum_traits::*;
+/// # fn main(){
+/// #[derive(Debug,PartialEq,EnumFromVariantName)]
+/// #[enum_traits(parse(case_insensitive))]
+/// enum Enum {
+/// 	#[enum_traits(alias = "Hound")]
+/// 	Dog,
+/// 	Cat,
+/// }
+/// assert_eq!(Enum::from_variant_name("dog"), Ok(Enum::Dog));
+/// assert_eq!(Enum::from_variant_name("DOG"), Ok(Enum::Dog));
+/// assert_eq!(Enum::from_variant_name("hound"), Ok(Enum::Dog));
+/// assert_eq!(Enum::from_variant_name("cAt"), Ok(Enum::Cat));
+/// # }
+/// ```
+#[proc_macro_derive(EnumFromVariantName,attributes(enum_traits))]
+pub fn derive_EnumFromVariantName(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	fn gen_impl(ident: &Ident,item: &DeriveInput,data: &[Variant],std: &Ident) -> syn::Result<TokenStream>{
+		let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
+		let type_name = ident.to_string();
+		let enum_attrs = attr::enum_attrs(&item.attrs)?;
+
+		//The constructor and the accepted names of every constructible variant. Folded when matching case insensitively.
+		let variant_names = {
+			let mut variant_names: Vec<(&Ident,TokenStream,Vec<String>)> = Vec::new();
+			let mut error: Option<Error> = None;
+			for variant in data.iter(){
+				let variant_attrs = match attr::variant_attrs(&variant.attrs){
+					Ok(variant_attrs) => variant_attrs,
+					Err(e) => {
+						match error{Some(ref mut error) => error.combine(e) , None => error = Some(e)}
+						continue;
+					}
+				};
+				if !is_constructible(variant,&enum_attrs,&variant_attrs){continue}
+				if variant_attrs.skip_parse{continue}
+				let constructor = variant_constructor(ident,variant,&enum_attrs,std,"EnumFromVariantName")?;
+
+				let mut names = Vec::new();
+				for name in iter::once(variant_attrs.name(&enum_attrs,&variant.ident)).chain(variant_attrs.aliases){
+					let name = if enum_attrs.parse_case_insensitive{fold_case(&name)}else{name};
+					if names.contains(&name){continue}
+					if let Some(&(other_ident,_,_)) = variant_names.iter().find(|(_,_,other_names)| other_names.contains(&name)){
+						let e = Error::new_spanned(variant,format!("`derive(EnumFromVariantName)`: The name \"{}\" of the variant `{}` is already used by the variant `{}`{}",name,variant.ident,other_ident,if enum_attrs.parse_case_insensitive{" (compared case insensitively)"}else{""}));
+						match error{Some(ref mut error) => error.combine(e) , None => error = Some(e)}
+						continue;
+					}
+					names.push(name);
+				}
+				variant_names.push((&variant.ident,constructor,names));
+			}
+			if let Some(error) = error{
+				return Err(error);
+			}
+			variant_names
+		};
+
+		let fn_from_variant_name = if enum_attrs.parse_case_insensitive{
+			let conditions = variant_names.iter().map(|(_,constructor,names)|{
+				quote! { if #( eq_folded(name,#names) )||* { #constructor } else }
+			});
+
+			quote!{
+				fn from_variant_name<'s>(name: &'s str) -> ::#std::result::Result<Self,::enum_traits::ParseVariantError<'s>>{
+					#[inline]
+					fn eq_folded(name: &str,folded: &str) -> bool{
+						name.chars().flat_map(char::to_lowercase).eq(folded.chars())
+					}
+
+					Ok(#( #conditions )* {
+						return Err(::enum_traits::ParseVariantError::new(name,#type_name))
+					})
+				}
+			}
+		}else{
+			let match_arms = variant_names.iter().map(|(_,constructor,names)|{
+				quote! { #( #names )|* => #constructor, }
+			});
+
+			quote!{
+				fn from_variant_name<'s>(name: &'s str) -> ::#std::result::Result<Self,::enum_traits::ParseVariantError<'s>>{
+					Ok(match name{
 						#( #match_arms )*
-						_ => return Err(())
+						_ => return Err(::enum_traits::ParseVariantError::new(name,#type_name))
 					})
 				}
 			}
-		}
+		};
+
+		Ok(quote!{
+			#[automatically_derived]
+			#[allow(unused_attributes)]
+			impl #impl_generics ::enum_traits::FromVariantName for #ident #ty_generics #where_clause{
+				#fn_from_variant_name
+			}
+
+			#[automatically_derived]
+			#[allow(unused_attributes)]
+			impl #impl_generics ::#std::str::FromStr for #ident #ty_generics #where_clause{
+				type Err = ::enum_traits::ParseVariantError<'static>;
+
+				#[inline]
+				fn from_str(str: &str) -> ::#std::result::Result<Self,Self::Err>{
+					<Self as ::enum_traits::FromVariantName>::from_variant_name(str).map_err(::enum_traits::ParseVariantError::into_owned)
+				}
+			}
+		})
 	}
-	derive_enum(input, gen_impl)
+	derive_enum(input,"EnumFromVariantName",gen_impl)
 }
 
 /// Implements `enum_traits::BitPattern`.
 ///
 /// # Requirements
 /// - The derived item is an enum
-#[proc_macro_derive(EnumBitPattern)]
-pub fn derive_EnumBitPattern(input: TokenStream) -> TokenStream{
-	fn variant_unit_ident(variant: &Variant) -> &Ident{
+/// - No variant is marked by `#[enum_traits(skip)]` or `#[enum_traits(skip(iter))]`, the same as in `derive(EnumToIndex)`
+///
+/// # Examples
+///
+/// A skipped variant is reported as an error at the variant:
+///
+/// ```compile_fail
+/// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
+/// # extern crate enum_traits;
+/// # fn main(){}
+/// #[derive(EnumBitPattern)]
+/// enum T{
+/// 	A,
+/// 	#[enum_traits(skip)]
+/// 	Invalid,
+/// }
+/// ```
+#[proc_macro_derive(EnumBitPattern,attributes(enum_traits))]
+pub fn derive_EnumBitPattern(input: proc_macro::TokenStream) -> proc_macro::TokenStream{
+	fn variant_unit_ident(variant: &Variant) -> syn::Result<&Ident>{
 		::variant_unit_ident(variant,"EnumBitPattern")
 	}
 
-	fn gen_impl(ident: &Ident,item: &MacroInput,data: &Vec<Variant>, _: Ident) -> Tokens{
+	fn gen_impl(ident: &Ident,item: &DeriveInput,data: &[Variant],_: &Ident) -> syn::Result<TokenStream>{
 		let (impl_generics,ty_generics,where_clause) = item.generics.split_for_impl();
 
+		check_no_skipped_variants(data,"EnumBitPattern")?;
+
 		let n = (data.len() as f64 / 8.0).ceil() as usize;
-		fn match_arm_transform(ident: &Ident,(i,variant_ident): (usize,&Ident),n: usize) -> Tokens{
-			let lit = Expr::from(ExprKind::Array({
-				let mut l = Vec::from_iter(iter::repeat(Expr::from(ExprKind::Lit(Lit::Int(0,IntTy::Unsuffixed)))).take(n));
-				l[n-i/8-1] = Expr::from(ExprKind::Lit(Lit::Int((0b00000001u8.rotate_left((i as u32)%8) as u64),IntTy::Unsuffixed)));
+		fn match_arm_transform(ident: &Ident,(i,variant_ident): (usize,&Ident),n: usize) -> TokenStream{
+			let lit = {
+				let mut l = vec![Literal::u8_unsuffixed(0);n];
+				l[n-i/8-1] = Literal::u8_unsuffixed(0b00000001u8.rotate_left((i as u32)%8));
 				l
-			}));
-			quote! { #ident::#variant_ident => #lit, }
+			};
+			quote! { #ident::#variant_ident => [#( #lit ),*], }
 		}
-		fn match_arm_transform_rev(ident: &Ident,(i,variant_ident): (usize,&Ident),n: usize) -> Tokens{
-			let lit = Expr::from(ExprKind::Array({
-				let mut l = Vec::from_iter(iter::repeat(Expr::from(ExprKind::Lit(Lit::Int(0,IntTy::Unsuffixed)))).take(n));
-				l[i/8] = Expr::from(ExprKind::Lit(Lit::Int((0b10000000u8.rotate_right((i as u32)%8) as u64),IntTy::Unsuffixed)));
+		fn match_arm_transform_rev(ident: &Ident,(i,variant_ident): (usize,&Ident),n: usize) -> TokenStream{
+			let lit = {
+				let mut l = vec![Literal::u8_unsuffixed(0);n];
+				l[i/8] = Literal::u8_unsuffixed(0b10000000u8.rotate_right((i as u32)%8));
 				l
-			}));
-			quote! { #ident::#variant_ident => #lit, }
+			};
+			quote! { #ident::#variant_ident => [#( #lit ),*], }
 		}
-		let match_arms     = data.iter().map(variant_unit_ident).enumerate().map(|arg| match_arm_transform(ident,arg,n));
-		let match_arms_rev = data.iter().map(variant_unit_ident).enumerate().map(|arg| match_arm_transform_rev(ident,arg,n));
+		let variant_idents = collect_results(data.iter().map(variant_unit_ident))?;
+		let match_arms     = variant_idents.iter().cloned().enumerate().map(|arg| match_arm_transform(ident,arg,n));
+		let match_arms_rev = variant_idents.iter().cloned().enumerate().map(|arg| match_arm_transform_rev(ident,arg,n));
 
-		quote!{
+		Ok(quote!{
 			#[automatically_derived]
 			#[allow(unused_attributes)]
 			impl #impl_generics ::enum_traits::BitPattern for #ident #ty_generics #where_clause{
@@ -987,21 +2276,40 @@ pub fn derive_EnumBitPattern(input: TokenStream) -> TokenStream{
 					}
 				}
 			}
-		}
+		})
 	}
-	derive_enum(input,gen_impl)
+	derive_enum(input,"EnumBitPattern",gen_impl)
 }
 
 /// Creates an enum with unit variants from the derived enum, and implements `enum_traits::Tag`.
+/// The created enum has no generic parameters, so every instantiation of a generic enum has the same tags.
+/// It derives `Copy`, `Clone`, `Debug`, `PartialEq`, `Eq` and `Hash`, and its variants have the same documentation and `#[cfg]`s as the variants of the derived enum.
+///
+/// The created enum also implements `enum_traits::Index`, `ToIndex`, `FromIndex`, `Len`, `Iterable` (see `derive(EnumIter)`) and `VariantName`.
+/// The iterator struct created for it is named after it followed by `Iter` (`EnumTagIter` for `Enum`), unless given by `tag(iter(name = "..."))`.
+/// Its names are the same as the names of the variants of the derived enum, as if those were derived for it.
+/// Every variant has a tag with an index in the defined order, including the variants marked by `#[enum_traits(skip)]`, so that every value of the derived enum has an index through its tag.
+/// Without skipped variants, the indices of the tags are the same as the ones of `derive(EnumFromIndex)` on the derived enum.
+/// After a skipped variant they are not, and `Len::LEN` of the created enum counts the skipped variants while the one of `derive(EnumLen)` does not.
+/// It has the primitive representation of the derived enum (`#[repr(u16)]` of `#[repr(C,u16)]`), if any.
+/// The derived enum implements `PartialEq` with the created enum, comparing the tag of the variant.
 ///
 /// # Requirements
 /// - The derived item is an enum
 ///
+/// # Attributes
+/// - `#[enum_traits(tag(name = "...",vis = "...",derive(...),iter(...)))]` on the enum: The created enum.
+///   `name` is its name (by default the name of the derived enum followed by `Tag`), `vis` is its visibility (by default the visibility of the derived enum) and `derive` are derives in addition to the default ones.
+///   `iter(name = "...",vis = "...",derive(...))` are the options of its iterator struct, the same as `#[enum_traits(iter(...))]` in `derive(EnumIter)`.
+///   `EnumIndex`, `EnumToIndex`, `EnumFromIndex`, `EnumLen`, `EnumIter` and `EnumVariantName` are always derived, so they are ignored in `derive` when given by their name or as `enum_traits_macros::...`.
+///   Derives from other paths are kept, even when they have the same name.
+///   Every option is optional, but the visibility may not be more restrictive than the visibility of the derived enum.
+///
 /// # Examples
 ///
 /// ```rust
-/// # #![feature(associated_consts)]
 /// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
 /// # extern crate enum_traits;
 /// # use enum_traits::*;
 /// # fn main(){
@@ -1014,51 +2322,149 @@ pub fn derive_EnumBitPattern(input: TokenStream) -> TokenStream{
 /// assert_eq!(EnumTag::Dog  ,Enum::Dog.tag());
 /// assert_eq!(EnumTag::Cat  ,Enum::Cat(0).tag());
 /// assert_eq!(EnumTag::Robot,Enum::Robot{speed: 0.0}.tag());
+///
+/// assert!(Enum::Cat(0) == EnumTag::Cat);
+/// assert!(Enum::Cat(0).is(EnumTag::Cat));
+/// assert_eq!(Enum::Robot{speed: 0.0}.tag_name(),"Robot");
+///
+/// //Counting the variants by their tags
+/// let mut counts = [0; EnumTag::LEN];
+/// for value in &[Enum::Cat(1),Enum::Dog,Enum::Cat(2)]{
+/// 	counts[value.tag().into_index() as usize]+= 1;
+/// }
+/// for tag in EnumTag::variants(){
+/// 	println!("{}: {}",tag.variant_name(),counts[tag.into_index() as usize]);
+/// }
+/// assert_eq!(counts,[1,2,0]);
+/// # }
+/// ```
+///
+/// ```rust
+/// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
+/// # extern crate enum_traits;
+/// # use enum_traits::*;
+/// # fn main(){
+/// #[derive(EnumTag)]
+/// #[enum_traits(tag(name = "Kind",vis = "pub(crate)",derive(PartialOrd,Ord)))]
+/// enum Shape{
+/// 	/// A circle with a radius
+/// 	Circle(f32),
+/// 	/// A rectangle with a width and a height
+/// 	Rectangle(f32,f32),
+/// }
+/// assert_eq!(Shape::Circle(1.0).tag(),Kind::Circle);
+/// assert!(Kind::Circle < Kind::Rectangle);
+/// assert_eq!(Kind::Rectangle.variant_name(),"Rectangle");
 /// # }
 /// ```
-#[proc_macro_derive(EnumTag)]
-pub fn derive_EnumTag(input: TokenStream) -> TokenStream{
-	fn gen_impl(ident: &Ident,item: &MacroInput,data: &Vec<Variant>, _: Ident) -> Tokens{
+#[proc_macro_derive(EnumTag,attributes(enum_traits))]
+pub fn derive_EnumTag(input: proc_macro::TokenStream) -> proc_macro::TokenStream{
+	type Derive = fn(proc_macro::TokenStream) -> proc_macro::TokenStream;
+
+	/// The derives implemented for the created enum
+	const TAG_DERIVES: &[(&str,Derive)] = &[
+		("EnumIndex"      ,derive_EnumIndex),
+		("EnumToIndex"    ,derive_EnumToIndex),
+		("EnumFromIndex"  ,derive_EnumFromIndex),
+		("EnumLen"        ,derive_EnumLen),
+		("EnumIter"       ,derive_EnumIter),
+		("EnumVariantName",derive_EnumVariantName),
+	];
+
+	fn gen_impl(ident: &Ident,item: &DeriveInput,data: &[Variant],std: &Ident) -> syn::Result<TokenStream>{
 		let (impl_generics,ty_generics,where_clause) = item.generics.split_for_impl();
-		let ref visibility = item.vis;
-
-		let unit_enum_ident = Ident::from({
-			const SUFFIX: &'static str = "Tag";
-			let mut str = String::with_capacity(ident.as_ref().len() + SUFFIX.len());
-			str.push_str(ident.as_ref());
-			str.push_str(SUFFIX);
-			str
+		let enum_attrs = attr::enum_attrs(&item.attrs)?;
+		let visibility = enum_attrs.tag.vis(&item.vis);
+		let doc = format!("The variants of `{}` without their fields",ident);
+
+		//The derives in `TAG_DERIVES` are already implemented, when named without a path or by their path in this crate
+		let derives = enum_attrs.tag.derives.iter().filter(|path|{
+			let in_crate = path.get_ident().is_some() || (path.segments.len() == 2 && path.segments[0].ident == "enum_traits_macros");
+			!(in_crate && TAG_DERIVES.iter().any(|&(name,_)| path.segments[path.segments.len() - 1].ident == name))
 		});
 
+		let unit_enum_ident = enum_attrs.tag.name(ident,"Tag");
+
+		//The options of the iterator struct of the created enum, given to `derive(EnumIter)`
+		let iter_attr = {
+			let name = LitStr::new(&enum_attrs.tag_iter.name(&unit_enum_ident,"Iter").to_string(),Span::call_site());
+			let vis = enum_attrs.tag_iter.vis.as_ref().map(|vis| LitStr::new(&quote!(#vis).to_string(),Span::call_site())).into_iter();
+			let derives = &enum_attrs.tag_iter.derives;
+			let derive = if derives.is_empty(){quote!{}}else{quote! { ,derive( #( #derives ),* ) }};
+			quote! { #[enum_traits(iter(name = #name #( ,vis = #vis )* #derive))] }
+		};
+
+		//The created enum has the primitive representation of the derived enum, making the types of the indices the same
+		let repr = match type_from_repr_attr(item.attrs.iter()){
+			Some(ty) => quote! { #[repr(#ty)] },
+			None => quote!{},
+		};
+
 		let match_arms = data.iter().map(|variant|{
 			let variant_ident = &variant.ident;
+			let cfgs = variant.attrs.iter().filter(|attr| attr.path().is_ident("cfg"));
 
-			match variant.data {
-				VariantData::Unit => {
-					quote! { &#ident::#variant_ident     => #unit_enum_ident::#variant_ident, }
+			match variant.fields {
+				Fields::Unit => {
+					quote! { #( #cfgs )* &#ident::#variant_ident     => #unit_enum_ident::#variant_ident, }
 				}
-				VariantData::Tuple(_) => {
-					quote! { &#ident::#variant_ident(..) => #unit_enum_ident::#variant_ident, }
+				Fields::Unnamed(_) => {
+					quote! { #( #cfgs )* &#ident::#variant_ident(..) => #unit_enum_ident::#variant_ident, }
 				}
-				VariantData::Struct(_) => {
-					quote! { &#ident::#variant_ident{..} => #unit_enum_ident::#variant_ident, }
+				Fields::Named(_) => {
+					quote! { #( #cfgs )* &#ident::#variant_ident{..} => #unit_enum_ident::#variant_ident, }
 				}
 			}
 		});
 
+		//The documentation and the conditional compilation of the variants are kept on the unit variants
 		let unit_variants = data.iter().map(|variant|{
 			let variant_ident = &variant.ident;
-			quote! { #variant_ident, }
+			let attrs = variant.attrs.iter().filter(|attr| attr.path().is_ident("doc") || attr.path().is_ident("cfg"));
+			quote! { #( #attrs )* #variant_ident, }
 		});
 
-		quote!{
+		//The derives in `TAG_DERIVES` are given an enum with the same variants, which are renamed in the same way as the variants of the derived enum.
+		//This makes the names of the tags the same as the ones of the variants. No tag is skipped, because every tag has an index.
+		let tag_impls: TokenStream = {
+			let variants = collect_results(data.iter().map(|variant| attr::variant_attrs(&variant.attrs).map(|variant_attrs|{
+				let variant_ident = &variant.ident;
+				let name = variant_attrs.name(&enum_attrs,variant_ident);
+				let cfgs = variant.attrs.iter().filter(|attr| attr.path().is_ident("cfg"));
+				quote! { #( #cfgs )* #[enum_traits(rename = #name)] #variant_ident, }
+			})))?;
+			let tag_item = quote!{
+				#repr
+				#iter_attr
+				#visibility enum #unit_enum_ident{
+					#( #variants )*
+				}
+			};
+			TAG_DERIVES.iter().map(|&(_,derive)| TokenStream::from(derive(tag_item.clone().into()))).collect()
+		};
+
+		Ok(quote!{
 			#[automatically_derived]
 			#[allow(unused_attributes)]
-			#[derive(Copy,Clone,Debug,PartialEq,Eq,Hash)]
+			#[doc = #doc]
+			#[derive(Copy,Clone,Debug,PartialEq,Eq,Hash #( ,#derives )*)]
+			#repr
 			#visibility enum #unit_enum_ident{
 				#( #unit_variants )*
 			}
 
+			#tag_impls
+
+			#[automatically_derived]
+			#[allow(unused_attributes)]
+			impl #impl_generics ::#std::cmp::PartialEq<#unit_enum_ident> for #ident #ty_generics #where_clause{
+				#[inline]
+				fn eq(&self,other: &#unit_enum_ident) -> bool{
+					::enum_traits::Tag::tag(self) == *other
+				}
+			}
+
 			#[automatically_derived]
 			#[allow(unused_attributes)]
 			impl #impl_generics ::enum_traits::Tag for #ident #ty_generics #where_clause{
@@ -1071,21 +2477,190 @@ pub fn derive_EnumTag(input: TokenStream) -> TokenStream{
 					}
 				}
 			}
-		}
+		})
+	}
+	derive_enum(input,"EnumTag",gen_impl)
+}
+
+/// Implements functions accessing the fields of the variants with fields.
+///
+/// For every variant with fields, where `variant` is the name of the variant converted to `snake_case` in the same way as in `derive(EnumIsVariantFns)`:
+/// - `fn as_variant(&self) -> Option<(&A,&B,...)>`: References to the fields, if the variant is the current one.
+/// - `fn as_variant_mut(&mut self) -> Option<(&mut A,&mut B,...)>`: Mutable references to the fields, if the variant is the current one.
+/// - `fn into_variant(self) -> Result<(A,B,...),Self>`: The fields, if the variant is the current one. Otherwise the enum is given back.
+/// - `fn expect_variant(self,msg: &str) -> (A,B,...)`: The fields. Panics with a message containing `msg`, the expected and the current variant (using `enum_traits::VariantName`) if the variant is not the current one.
+///
+/// The fields are in the defined order, for both tuple and struct variants.
+/// Variants with a single field give the field instead of an 1-tuple.
+///
+/// # Requirements
+/// - The derived item is an enum
+/// - No two variants have functions with the same name
+/// - `expect_*` is only callable when the enum implements `enum_traits::VariantName`
+///
+/// # Attributes
+/// - `#[enum_traits(rename_all = "...")]` on the enum and `#[enum_traits(rename = "...")]` on a variant: The names of the variants, the same as in `derive(EnumVariantName)`.
+///   `#[enum_traits(is_fn(...))]` and `#[enum_traits(constructor(...))]` do not apply.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
+/// # extern crate enum_traits;
+/// # fn main(){
+/// #[derive(Debug,PartialEq,EnumAccessors,EnumVariantName)]
+/// enum Message {
+/// 	Quit,
+/// 	Move{x: i32,y: i32},
+/// 	Write(String),
+/// }
+///
+/// let mut message = Message::Move{x: 1,y: 2};
+/// assert_eq!(message.as_move(), Some((&1,&2)));
+/// assert_eq!(message.as_write(), None);
+/// if let Some((x,_)) = message.as_move_mut(){
+/// 	*x = 10;
+/// }
+/// assert_eq!(message.into_move(), Ok((10,2)));
+///
+/// assert_eq!(Message::Write(String::from("Hello")).expect_write("a text message"), "Hello");
+/// assert_eq!(Message::Quit.into_write(), Err(Message::Quit));
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
+/// # extern crate enum_traits;
+/// # fn main(){
+/// #[derive(EnumAccessors,EnumVariantName)]
+/// enum Message {
+/// 	Quit,
+/// 	Write(String),
+/// }
+/// //Panics with "a text message: expected the variant `Write`, found `Quit`"
+/// Message::Quit.expect_write("a text message");
+/// # }
+/// ```
+#[proc_macro_derive(EnumAccessors,attributes(enum_traits))]
+pub fn derive_EnumAccessors(input: proc_macro::TokenStream) -> proc_macro::TokenStream{
+	fn gen_impl(ident: &Ident,item: &DeriveInput,data: &[Variant],std: &Ident) -> syn::Result<TokenStream>{
+		let (impl_generics,ty_generics,where_clause) = item.generics.split_for_impl();
+		let enum_attrs = attr::enum_attrs(&item.attrs)?;
+		let variants_attrs = collect_results(data.iter().map(|variant| attr::variant_attrs(&variant.attrs)))?;
+
+		let variants: Vec<(&Variant,&attr::VariantAttrs,[Ident; 4])> = collect_results(data.iter().zip(variants_attrs.iter()).filter(|&(variant,_)| !variant.fields.is_empty()).map(|(variant,variant_attrs)|{
+			let fn_name = variant_fn_name(variant,variant_attrs,&enum_attrs);
+			Ok((variant,variant_attrs,[
+				variant_fn_ident(&format!("as_{}"    ,fn_name),variant,"EnumAccessors")?,
+				variant_fn_ident(&format!("as_{}_mut",fn_name),variant,"EnumAccessors")?,
+				variant_fn_ident(&format!("into_{}"  ,fn_name),variant,"EnumAccessors")?,
+				variant_fn_ident(&format!("expect_{}",fn_name),variant,"EnumAccessors")?,
+			]))
+		}))?;
+		check_fn_collisions(&variants.iter().flat_map(|&(variant,_,ref fn_idents)| fn_idents.iter().map(move |fn_ident| (fn_ident.clone(),variant))).collect::<Vec<_>>(),"EnumAccessors")?;
+
+		let fns = variants.iter().map(|&(variant,variant_attrs,ref fn_idents)|{
+			let variant_ident = &variant.ident;
+			let name = variant_attrs.name(&enum_attrs,variant_ident);
+			let [fn_as,fn_as_mut,fn_into,fn_expect] = fn_idents;
+
+			let bindings: Vec<Ident> = (0..variant.fields.len()).map(|i| format_ident!("field_{}",i)).collect();
+			let types: Vec<&syn::Type> = variant.fields.iter().map(|field| &field.ty).collect();
+
+			//The patterns binding the fields by value, by reference and by mutable reference
+			let pattern = |binding_mode: TokenStream|{
+				let bindings = bindings.iter().map(|binding| quote! { #binding_mode #binding });
+				match variant.fields{
+					Fields::Named(ref fields) => {
+						let names = fields.named.iter().map(|field| &field.ident);
+						quote! { #ident::#variant_ident{ #( #names: #bindings ),* } }
+					}
+					_ => quote! { #ident::#variant_ident( #( #bindings ),* ) },
+				}
+			};
+			let pattern_value   = pattern(quote!{});
+			let pattern_ref     = pattern(quote! { ref });
+			let pattern_ref_mut = pattern(quote! { ref mut });
+
+			//A single field is given as it is, and multiple fields as a tuple
+			let (value,ty,ty_ref,ty_ref_mut) = if bindings.len() == 1{
+				let (binding,ty) = (&bindings[0],types[0]);
+				(quote! { #binding },quote! { #ty },quote! { &#ty },quote! { &mut #ty })
+			}else{
+				(quote! { ( #( #bindings ),* ) },quote! { ( #( #types ),* ) },quote! { ( #( &#types ),* ) },quote! { ( #( &mut #types ),* ) })
+			};
+
+			quote! {
+				#[inline]
+				#[allow(dead_code,unreachable_patterns)]
+				pub fn #fn_as(&self) -> ::#std::option::Option<#ty_ref>{
+					match *self{
+						#pattern_ref => ::#std::option::Option::Some(#value),
+						_ => ::#std::option::Option::None,
+					}
+				}
+
+				#[inline]
+				#[allow(dead_code,unreachable_patterns)]
+				pub fn #fn_as_mut(&mut self) -> ::#std::option::Option<#ty_ref_mut>{
+					match *self{
+						#pattern_ref_mut => ::#std::option::Option::Some(#value),
+						_ => ::#std::option::Option::None,
+					}
+				}
+
+				#[inline]
+				#[allow(dead_code,unreachable_patterns)]
+				pub fn #fn_into(self) -> ::#std::result::Result<#ty,Self>{
+					match self{
+						#pattern_value => ::#std::result::Result::Ok(#value),
+						other => ::#std::result::Result::Err(other),
+					}
+				}
+
+				//The bound is higher-ranked to be checked when calling the function instead of being an error for enums not implementing `VariantName`
+				#[inline]
+				#[allow(dead_code,unreachable_patterns)]
+				pub fn #fn_expect(self,msg: &str) -> #ty where for<'enum_traits> Self: ::enum_traits::VariantName{
+					match self{
+						#pattern_value => #value,
+						other => panic!("{}: expected the variant `{}`, found `{}`",msg,#name,::enum_traits::VariantName::variant_name(&other)),
+					}
+				}
+			}
+		});
+
+		Ok(quote!{
+			#[automatically_derived]
+			#[allow(unused_attributes)]
+			impl #impl_generics #ident #ty_generics #where_clause{
+				#( #fns )*
+			}
+		})
 	}
-	derive_enum(input,gen_impl)
+	derive_enum(input,"EnumAccessors",gen_impl)
 }
 
 /// Implements functions that checks if the current state of the enum is a certain variant.
+/// The function of a variant is named `is_` followed by the name of the variant converted to `snake_case` (`RobotArm` is `is_robot_arm` and `HTTPServer` is `is_http_server`).
 ///
 /// # Requirements
 /// - The derived item is an enum
+/// - No two variants have functions with the same name
+///
+/// # Attributes
+/// - `#[enum_traits(rename_all = "...")]` on the enum and `#[enum_traits(rename = "...")]` on a variant: The names of the variants, the same as in `derive(EnumVariantName)`.
+/// - `#[enum_traits(is_fn(prefix = "..."))]` on the enum: The prefix of every function instead of `is_`.
+/// - `#[enum_traits(is_fn(prefix = "...",name = "..."))]` on a variant: The prefix and the name following the prefix of the function of the variant.
+///   The name is used as it is, without converting it to `snake_case`. Both options are optional.
 ///
 /// # Examples
 ///
 /// ```rust
-/// # #![feature(associated_consts)]
 /// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
 /// # extern crate enum_traits;
 /// # use enum_traits::*;
 /// # fn main(){
@@ -1107,32 +2682,79 @@ pub fn derive_EnumTag(input: TokenStream) -> TokenStream{
 /// assert!(!Enum::Cat(0).is_robot());
 /// # }
 /// ```
-#[cfg(not(feature = "no_std_compile"))]
-#[proc_macro_derive(EnumIsVariantFns)]
-pub fn derive_EnumIsVariantFns(input: TokenStream) -> TokenStream{
-	fn gen_impl(ident: &Ident,item: &MacroInput,data: &Vec<Variant>, _: Ident) -> Tokens{
+///
+/// ```rust
+/// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
+/// # fn main(){
+/// #[derive(EnumIsVariantFns)]
+/// #[enum_traits(rename_all = "snake_case")]
+/// enum Enum {
+/// 	GuideDog,
+/// 	#[enum_traits(rename = "Kitten")]
+/// 	Cat(i32),
+/// }
+/// assert!(Enum::GuideDog.is_guide_dog());
+/// assert!(Enum::Cat(0).is_kitten());
+/// # }
+/// ```
+///
+/// ```rust
+/// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
+/// # fn main(){
+/// #[derive(EnumIsVariantFns)]
+/// #[enum_traits(is_fn(prefix = "is_state_"))]
+/// enum State {
+/// 	HTTPRequest,
+/// 	Md5Hash,
+/// 	#[enum_traits(is_fn(prefix = "",name = "has_finished"))]
+/// 	Done(i32),
+/// }
+/// assert!(State::HTTPRequest.is_state_http_request());
+/// assert!(State::Md5Hash.is_state_md5_hash());
+/// assert!(State::Done(0).has_finished());
+/// # }
+/// ```
+///
+/// Two variants with functions of the same name are rejected:
+///
+/// ```compile_fail
+/// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
+/// # fn main(){}
+/// #[derive(EnumIsVariantFns)]
+/// enum Enum {
+/// 	HttpServer,
+/// 	HTTPServer,
+/// }
+/// ```
+#[proc_macro_derive(EnumIsVariantFns,attributes(enum_traits))]
+pub fn derive_EnumIsVariantFns(input: proc_macro::TokenStream) -> proc_macro::TokenStream{
+	fn gen_impl(ident: &Ident,item: &DeriveInput,data: &[Variant],_: &Ident) -> syn::Result<TokenStream>{
 		let (impl_generics,ty_generics,where_clause) = item.generics.split_for_impl();
+		let enum_attrs = attr::enum_attrs(&item.attrs)?;
+		let variants_attrs = collect_results(data.iter().map(|variant| attr::variant_attrs(&variant.attrs)))?;
 
+		let fn_idents = collect_results(data.iter().zip(variants_attrs.iter()).map(|(variant,variant_attrs)|{
+			let prefix = variant_attrs.is_fn.prefix(&enum_attrs.is_fn,"is_");
+			let name = variant_attrs.is_fn.name.clone().unwrap_or_else(|| variant_fn_name(variant,variant_attrs,&enum_attrs));
+			variant_fn_ident(&format!("{}{}",prefix,name),variant,"EnumIsVariantFns").map(|fn_ident| (fn_ident,variant))
+		}))?;
+		check_fn_collisions(&fn_idents,"EnumIsVariantFns")?;
 
-		let fns = data.iter().map(|variant|{
-			let fn_ident = Ident::from({
-				const PREFIX: &'static str = "is_";
-				let mut str = String::with_capacity(variant.ident.as_ref().len() + PREFIX.len());
-				str.push_str(PREFIX);
-				str.push_str(variant.ident.as_ref().to_ascii_lowercase().as_ref());
-				str
-			});
+		let fns = fn_idents.iter().map(|&(ref fn_ident,variant)|{
 
 			let pattern = {
 				let variant_ident = &variant.ident;
-				match variant.data{
-					VariantData::Unit => {
+				match variant.fields{
+					Fields::Unit => {
 						quote! { #ident::#variant_ident }
 					}
-					VariantData::Tuple(_) => {
+					Fields::Unnamed(_) => {
 						quote! { #ident::#variant_ident(..) }
 					}
-					VariantData::Struct(_) => {
+					Fields::Named(_) => {
 						quote! { #ident::#variant_ident{..} }
 					}
 				}
@@ -1147,13 +2769,349 @@ pub fn derive_EnumIsVariantFns(input: TokenStream) -> TokenStream{
 			}
 		});
 
-		quote!{
+		Ok(quote!{
 			#[automatically_derived]
 			#[allow(unused_attributes)]
 			impl #impl_generics #ident #ty_generics #where_clause{
 				#( #fns )*
 			}
-		}
+		})
+	}
+	derive_enum(input,"EnumIsVariantFns",gen_impl)
+}
+
+/// Implements functions constructing the variants, and a table of the constructors of the tuple variants.
+///
+/// For every variant, where `variant` is the name of the variant converted to `snake_case` in the same way as in `derive(EnumIsVariantFns)`:
+/// - `fn variant(a: A,b: B,...) -> Self`: Constructs the variant from its fields in the defined order.
+///   The parameters of a struct variant are named after its fields, and the parameters of a tuple variant are named `field_0`, `field_1`, ...
+///   Names that are keywords (`type` from the variant `Type`) are raw identifiers (`r#type`).
+///
+/// When the enum has tuple variants, and every tuple variant has fields of the same types:
+/// - `const CONSTRUCTORS: &'static [(&'static str,fn(A,B,...) -> Self)]`: The names (the same as in `derive(EnumVariantName)`) and the constructors of the tuple variants, in the defined order.
+///   Generic enums have the table only when `'static`.
+///
+/// # Requirements
+/// - The derived item is an enum
+/// - No two variants have functions with the same name
+///
+/// # Attributes
+/// - `#[enum_traits(rename_all = "...")]` on the enum and `#[enum_traits(rename = "...")]` on a variant: The names of the variants, the same as in `derive(EnumVariantName)`.
+/// - `#[enum_traits(constructor(prefix = "...",into))]` on the enum: The prefix of every function (none by default), and whether the parameters are `impl Into<A>` of the types of the fields. Both options are optional.
+/// - `#[enum_traits(constructor(prefix = "...",name = "...",into))]` on a variant: The prefix, the name following the prefix and whether the parameters are `impl Into<A>`, for the function of the variant.
+///   The name is used as it is, without converting it to `snake_case`. All options are optional.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
+/// # fn main(){
+/// #[derive(Debug,PartialEq,EnumConstructors)]
+/// enum Enum {
+/// 	Dog,
+/// 	Cat(i32),
+/// 	Robot{speed: f32,name: String},
+/// }
+/// assert_eq!(Enum::dog(), Enum::Dog);
+/// assert_eq!(Enum::cat(1), Enum::Cat(1));
+/// assert_eq!(Enum::robot(1.0,String::from("R2")), Enum::Robot{speed: 1.0,name: String::from("R2")});
+/// # }
+/// ```
+///
+/// ```rust
+/// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
+/// # fn main(){
+/// #[derive(Debug,PartialEq,EnumConstructors)]
+/// #[enum_traits(constructor(prefix = "new_",into))]
+/// enum Shape {
+/// 	Circle{radius: f64},
+/// 	#[enum_traits(constructor(prefix = "",name = "label"))]
+/// 	Label(String),
+/// }
+/// assert_eq!(Shape::new_circle(2.0f32), Shape::Circle{radius: 2.0});
+/// assert_eq!(Shape::label("text"), Shape::Label(String::from("text")));
+/// # }
+/// ```
+///
+/// ```rust
+/// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
+/// # fn main(){
+/// #[derive(Debug,PartialEq,EnumConstructors)]
+/// enum Op {
+/// 	Push(i32),
+/// 	Pop(i32),
+/// 	Halt,
+/// }
+/// let (name,constructor) = Op::CONSTRUCTORS[1];
+/// assert_eq!(name, "Pop");
+/// assert_eq!(constructor(3), Op::Pop(3));
+/// assert_eq!(Op::CONSTRUCTORS.len(), 2);
+/// # }
+/// ```
+///
+/// Two variants with functions of the same name are rejected:
+///
+/// ```compile_fail
+/// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
+/// # fn main(){}
+/// #[derive(EnumConstructors)]
+/// enum Enum {
+/// 	HttpServer(u16),
+/// 	HTTPServer(u16),
+/// }
+/// ```
+#[proc_macro_derive(EnumConstructors,attributes(enum_traits))]
+pub fn derive_EnumConstructors(input: proc_macro::TokenStream) -> proc_macro::TokenStream{
+	fn gen_impl(ident: &Ident,item: &DeriveInput,data: &[Variant],std: &Ident) -> syn::Result<TokenStream>{
+		let (impl_generics,ty_generics,where_clause) = item.generics.split_for_impl();
+		let enum_attrs = attr::enum_attrs(&item.attrs)?;
+		let variants_attrs = collect_results(data.iter().map(|variant| attr::variant_attrs(&variant.attrs)))?;
+
+		let fn_idents = collect_results(data.iter().zip(variants_attrs.iter()).map(|(variant,variant_attrs)|{
+			let prefix = variant_attrs.constructor.prefix(&enum_attrs.constructor,"");
+			let name = variant_attrs.constructor.name.clone().unwrap_or_else(|| variant_fn_name(variant,variant_attrs,&enum_attrs));
+			variant_fn_ident(&format!("{}{}",prefix,name),variant,"EnumConstructors").map(|fn_ident| (fn_ident,variant))
+		}))?;
+		check_fn_collisions(&fn_idents,"EnumConstructors")?;
+
+		let fns = fn_idents.iter().zip(variants_attrs.iter()).map(|(&(ref fn_ident,variant),variant_attrs)|{
+			let variant_ident = &variant.ident;
+			let into = variant_attrs.constructor.into || enum_attrs.constructor.into;
+
+			let params: Vec<Ident> = variant.fields.iter().enumerate().map(|(i,field)| match field.ident{
+				Some(ref field_ident) => field_ident.clone(),
+				None => format_ident!("field_{}",i),
+			}).collect();
+			let (param_types,values): (Vec<TokenStream>,Vec<TokenStream>) = variant.fields.iter().zip(params.iter()).map(|(field,param)|{
+				let ty = &field.ty;
+				if into{
+					(quote! { impl ::#std::convert::Into<#ty> },quote! { ::#std::convert::Into::into(#param) })
+				}else{
+					(quote! { #ty },quote! { #param })
+				}
+			}).unzip();
+
+			let value = match variant.fields{
+				Fields::Unit => quote! { #ident::#variant_ident },
+				Fields::Unnamed(_) => quote! { #ident::#variant_ident( #( #values ),* ) },
+				Fields::Named(_) => quote! { #ident::#variant_ident{ #( #params: #values ),* } },
+			};
+
+			quote! {
+				#[inline]
+				#[must_use]
+				#[allow(dead_code)]
+				pub fn #fn_ident( #( #params: #param_types ),* ) -> Self{
+					#value
+				}
+			}
+		});
+
+		//The table exists when every tuple variant has fields of the same types, compared by their tokens
+		let tuple_variants: Vec<(&Variant,&attr::VariantAttrs)> = data.iter().zip(variants_attrs.iter()).filter(|&(variant,_)| matches!(variant.fields,Fields::Unnamed(_))).collect();
+		let field_types = |variant: &Variant| variant.fields.iter().map(|field|{
+			let ty = &field.ty;
+			quote!{#ty}.to_string()
+		}).collect::<Vec<String>>();
+		let impl_constructors = match tuple_variants.first(){
+			Some(&(first,_)) if tuple_variants.iter().all(|&(variant,_)| field_types(variant) == field_types(first)) => {
+				let types = first.fields.iter().map(|field| &field.ty);
+				let entries = tuple_variants.iter().map(|&(variant,variant_attrs)|{
+					let variant_ident = &variant.ident;
+					let name = variant_attrs.name(&enum_attrs,variant_ident);
+					quote! { (#name,#ident::#variant_ident) }
+				});
+
+				let mut generics = item.generics.clone();
+				if !generics.params.is_empty(){
+					generics.make_where_clause().predicates.push(parse_quote!(#ident #ty_generics: 'static));
+				}
+				let (impl_generics,_,where_clause) = generics.split_for_impl();
+				quote!{
+					#[automatically_derived]
+					#[allow(unused_attributes)]
+					impl #impl_generics #ident #ty_generics #where_clause{
+						#[allow(dead_code)]
+						pub const CONSTRUCTORS: &'static [(&'static str,fn( #( #types ),* ) -> Self)] = &[ #( #entries ),* ];
+					}
+				}
+			}
+			_ => quote!{},
+		};
+
+		Ok(quote!{
+			#[automatically_derived]
+			#[allow(unused_attributes)]
+			impl #impl_generics #ident #ty_generics #where_clause{
+				#( #fns )*
+			}
+
+			#impl_constructors
+		})
+	}
+	derive_enum(input,"EnumConstructors",gen_impl)
+}
+
+/// Creates a struct of the fields of every variant with fields, and implements conversions between the structs and the enum.
+///
+/// For every variant with fields, where `EnumVariant` is the name of the derived enum followed by the name of the variant (`Robot` of `Enum` is `EnumRobot`):
+/// - `struct EnumVariant`: A struct with the same fields as the variant, in the same order and of the same kind (a tuple struct for a tuple variant).
+///   The fields have the visibility of the fields of the variant, which is the visibility of the enum.
+///   The struct only has the generic parameters of the enum that its fields refer to.
+/// - `impl From<EnumVariant> for Enum`: Constructs the variant from the struct.
+/// - `impl TryFrom<Enum> for EnumVariant`: The fields of the variant as the struct, if the variant is the current one. Otherwise the error is the enum given back.
+///
+/// The documentation and the conditional compilation of the variants and the documentation of the fields are kept on the structs.
+/// Structs of undocumented variants are documented as the fields of the variant.
+///
+/// # Requirements
+/// - The derived item is an enum
+///
+/// # Attributes
+/// - `#[enum_traits(variant_struct(vis = "...",derive(...)))]` on the enum: The visibility of every struct (by default the visibility of the derived enum) and the derives of every struct.
+///   Both options are optional.
+/// - `#[enum_traits(variant_struct(name = "...",vis = "...",derive(...)))]` on a variant: The name, the visibility and the additional derives of the struct of the variant.
+///   Every option is optional. Derives on the enum and on the variant are both used.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
+/// # use std::convert::TryFrom;
+/// # fn main(){
+/// #[derive(Debug,PartialEq,EnumVariantStructs)]
+/// #[enum_traits(variant_struct(derive(Debug,PartialEq)))]
+/// enum Enum{
+/// 	Dog,
+/// 	Cat(i32),
+/// 	Robot{speed: f32},
+/// }
+///
+/// fn handle(robot: EnumRobot) -> f32{
+/// 	robot.speed
+/// }
+///
+/// assert_eq!(Enum::from(EnumCat(1)), Enum::Cat(1));
+/// assert_eq!(EnumRobot::try_from(Enum::Robot{speed: 2.0}).map(handle), Ok(2.0));
+/// assert_eq!(EnumRobot::try_from(Enum::Dog), Err(Enum::Dog));
+/// # }
+/// ```
+///
+/// ```rust
+/// # #[macro_use]extern crate enum_traits_macros;
+/// # extern crate core;
+/// # use std::convert::TryFrom;
+/// # fn main(){
+/// #[derive(EnumVariantStructs)]
+/// enum Message<'t,T>{
+/// 	Text(&'t str),
+/// 	#[enum_traits(variant_struct(name = "Payload",derive(Clone)))]
+/// 	Data{len: usize,data: T},
+/// }
+///
+/// let text: MessageText = MessageText("Hello");
+/// let payload: Payload<[u8; 2]> = Payload{len: 2,data: [1,2]};
+/// match Message::from(payload.clone()){
+/// 	Message::Data{len,..} => assert_eq!(len, 2),
+/// 	Message::Text(_) => unreachable!(),
+/// }
+/// assert!(MessageText::try_from(Message::<()>::from(text)).is_ok());
+/// # }
+/// ```
+#[proc_macro_derive(EnumVariantStructs,attributes(enum_traits))]
+pub fn derive_EnumVariantStructs(input: proc_macro::TokenStream) -> proc_macro::TokenStream{
+	fn gen_impl(ident: &Ident,item: &DeriveInput,data: &[Variant],std: &Ident) -> syn::Result<TokenStream>{
+		let (impl_generics,ty_generics,where_clause) = item.generics.split_for_impl();
+		let enum_attrs = attr::enum_attrs(&item.attrs)?;
+		let field_visibility = &item.vis;
+
+		let structs = collect_results(data.iter().filter(|variant| !variant.fields.is_empty()).map(|variant|{
+			let variant_ident = &variant.ident;
+			let variant_attrs = attr::variant_attrs(&variant.attrs)?;
+			let struct_ident = variant_attrs.variant_struct.name(ident,&variant_ident.to_string());
+			let visibility = variant_attrs.variant_struct.vis(enum_attrs.variant_struct.vis(&item.vis));
+			let derives = enum_attrs.variant_struct.derives.iter().chain(variant_attrs.variant_struct.derives.iter());
+
+			let struct_generics = variant_generics(&item.generics,variant);
+			let (_,struct_ty_generics,struct_where_clause) = struct_generics.split_for_impl();
+
+			//The documentation and the conditional compilation of the variant are kept on the struct and the conversions
+			let docs: Vec<&Attribute> = variant.attrs.iter().filter(|attr| attr.path().is_ident("doc")).collect();
+			let doc = if docs.is_empty(){
+				let doc = format!("The fields of the variant `{}::{}`",ident,variant_ident);
+				quote! { #[doc = #doc] }
+			}else{
+				quote! { #( #docs )* }
+			};
+			let cfgs: Vec<&Attribute> = variant.attrs.iter().filter(|attr| attr.path().is_ident("cfg")).collect();
+
+			let fields = variant.fields.iter().map(|field|{
+				let docs = field.attrs.iter().filter(|attr| attr.path().is_ident("doc"));
+				let field_ident = &field.ident;
+				let colon = field.colon_token;
+				let ty = &field.ty;
+				quote! { #( #docs )* #field_visibility #field_ident #colon #ty }
+			});
+			let bindings: Vec<Ident> = (0..variant.fields.len()).map(|i| format_ident!("field_{}",i)).collect();
+			let (struct_def,struct_pattern,variant_pattern) = match variant.fields{
+				Fields::Named(ref named) => {
+					let names: Vec<&Option<Ident>> = named.named.iter().map(|field| &field.ident).collect();
+					(
+						quote! { #struct_where_clause{ #( #fields ),* } },
+						quote! { #struct_ident{ #( #names: #bindings ),* } },
+						quote! { #ident::#variant_ident{ #( #names: #bindings ),* } },
+					)
+				}
+				_ => (
+					quote! { ( #( #fields ),* ) #struct_where_clause; },
+					quote! { #struct_ident( #( #bindings ),* ) },
+					quote! { #ident::#variant_ident( #( #bindings ),* ) },
+				),
+			};
+
+			Ok(quote!{
+				#doc
+				#( #cfgs )*
+				#[derive( #( #derives ),* )]
+				#visibility struct #struct_ident #struct_generics #struct_def
+
+				#( #cfgs )*
+				#[automatically_derived]
+				#[allow(unused_attributes)]
+				impl #impl_generics ::#std::convert::From<#struct_ident #struct_ty_generics> for #ident #ty_generics #where_clause{
+					#[inline]
+					fn from(value: #struct_ident #struct_ty_generics) -> Self{
+						let #struct_pattern = value;
+						#variant_pattern
+					}
+				}
+
+				#( #cfgs )*
+				#[automatically_derived]
+				#[allow(unused_attributes)]
+				impl #impl_generics ::#std::convert::TryFrom<#ident #ty_generics> for #struct_ident #struct_ty_generics #where_clause{
+					type Error = #ident #ty_generics;
+
+					#[inline]
+					#[allow(unreachable_patterns)]
+					fn try_from(value: #ident #ty_generics) -> ::#std::result::Result<Self,Self::Error>{
+						match value{
+							#variant_pattern => ::#std::result::Result::Ok(#struct_pattern),
+							other => ::#std::result::Result::Err(other),
+						}
+					}
+				}
+			})
+		}))?;
+
+		Ok(quote!{
+			#( #structs )*
+		})
 	}
-	derive_enum(input,gen_impl)
+	derive_enum(input,"EnumVariantStructs",gen_impl)
 }
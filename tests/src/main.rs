@@ -3,7 +3,6 @@
 
 #![cfg_attr(feature = "no_std" ,no_std)]
 
-#![cfg_attr(feature = "nightly" ,feature(associated_consts))]
 #![allow(unreachable_code)]
 
 #[cfg(not(feature = "no_std"))]extern crate core;
@@ -17,8 +16,7 @@ use enum_traits::*;
 mod fields{
 	use enum_traits::*;
 
-	#[cfg_attr(feature = "no_std_compile"      ,derive(Debug,Eq,PartialEq,EnumIndex,              EnumToIndex,EnumLen,EnumDiscriminant,                 EnumTag,                               EnumVariantName,EnumFromVariantName))]
-	#[cfg_attr(not(feature = "no_std_compile") ,derive(Debug,Eq,PartialEq,EnumIndex,              EnumToIndex,EnumLen,EnumDiscriminant,EnumIsVariantFns,EnumTag,                               EnumVariantName,EnumFromVariantName))]
+	#[derive(Debug,Eq,PartialEq,EnumIndex,EnumToIndex,EnumLen,EnumDiscriminant,EnumIsVariantFns,EnumTag,EnumVariantName,EnumFromVariantName)]
 	enum Fields<'t,T: 't>{
 		VariantA(&'t T),
 		VariantB(T),
@@ -73,64 +71,71 @@ mod fields{
 		assert_eq!(None,Fields::<'static,()>::from_discriminant(2));
 		assert_eq!(None,Fields::<'static,()>::from_discriminant(3));
 		assert_eq!(None,Fields::<'static,()>::from_discriminant(4));
-		assert_eq!(None,Fields::<'static,()>::from_discriminant(5));
+		assert_eq!(Some(Fields::VariantF),Fields::<'static,()>::from_discriminant(5));
 		assert_eq!(None,Fields::<'static,()>::from_discriminant(6));
 		assert_eq!(None,Fields::<'static,()>::from_discriminant(7));
 		assert_eq!(None,Fields::<'static,()>::from_discriminant(8));
 		assert_eq!(None,Fields::<'static,()>::from_discriminant(9));
+
+		let i = 0u8;
+		assert_eq!(0,Fields::VariantA(&i).discriminant());
+		assert_eq!(1,Fields::VariantB(0).discriminant());
+		assert_eq!(2,Fields::VariantC(0,1,2,3).discriminant());
+		assert_eq!(3,Fields::VariantD::<'static,()>{d: 0}.discriminant());
+		assert_eq!(4,Fields::VariantE::<'static,()>{a: 0,b: 1,c: 2,d: 3,e: 4}.discriminant());
+		assert_eq!(5,Fields::VariantF::<'static,()>.discriminant());
 	}
 
-	#[cfg(not(feature = "no_std_compile"))]
 	#[test]
 	fn test_isvariantfns(){
 		let i = 0u8;
 		let mut e = Fields::VariantA(&i);
-		assert!(e.is_varianta());
-		assert!(!e.is_variantb());
-		assert!(!e.is_variantc());
-		assert!(!e.is_variantd());
-		assert!(!e.is_variante());
-		assert!(!e.is_variantf());
+		assert!(e.is_variant_a());
+		assert!(!e.is_variant_b());
+		assert!(!e.is_variant_c());
+		assert!(!e.is_variant_d());
+		assert!(!e.is_variant_e());
+		assert!(!e.is_variant_f());
 
 		e = Fields::VariantB(0);
-		assert!(!e.is_varianta());
-		assert!(e.is_variantb());
-		assert!(!e.is_variantc());
-		assert!(!e.is_variantd());
-		assert!(!e.is_variante());
-		assert!(!e.is_variantf());
+		assert!(!e.is_variant_a());
+		assert!(e.is_variant_b());
+		assert!(!e.is_variant_c());
+		assert!(!e.is_variant_d());
+		assert!(!e.is_variant_e());
+		assert!(!e.is_variant_f());
 
 		e = Fields::VariantC(0,1,2,3);
-		assert!(!e.is_varianta());
-		assert!(!e.is_variantb());
-		assert!(e.is_variantc());
-		assert!(!e.is_variantd());
-		assert!(!e.is_variante());
-		assert!(!e.is_variantf());
+		assert!(!e.is_variant_a());
+		assert!(!e.is_variant_b());
+		assert!(e.is_variant_c());
+		assert!(!e.is_variant_d());
+		assert!(!e.is_variant_e());
+		assert!(!e.is_variant_f());
 
 		e = Fields::VariantD{d: 0};
-		assert!(!e.is_varianta());
-		assert!(!e.is_variantb());
-		assert!(!e.is_variantc());
-		assert!(e.is_variantd());
-		assert!(!e.is_variante());
-		assert!(!e.is_variantf());
+		assert!(!e.is_variant_a());
+		assert!(!e.is_variant_b());
+		assert!(!e.is_variant_c());
+		assert!(e.is_variant_d());
+		assert!(!e.is_variant_e());
+		assert!(!e.is_variant_f());
 
 		e = Fields::VariantE{a: 0,b: 1,c: 2,d: 3,e: 4};
-		assert!(!e.is_varianta());
-		assert!(!e.is_variantb());
-		assert!(!e.is_variantc());
-		assert!(!e.is_variantd());
-		assert!(e.is_variante());
-		assert!(!e.is_variantf());
+		assert!(!e.is_variant_a());
+		assert!(!e.is_variant_b());
+		assert!(!e.is_variant_c());
+		assert!(!e.is_variant_d());
+		assert!(e.is_variant_e());
+		assert!(!e.is_variant_f());
 
 		e = Fields::VariantF;
-		assert!(!e.is_varianta());
-		assert!(!e.is_variantb());
-		assert!(!e.is_variantc());
-		assert!(!e.is_variantd());
-		assert!(!e.is_variante());
-		assert!(e.is_variantf());
+		assert!(!e.is_variant_a());
+		assert!(!e.is_variant_b());
+		assert!(!e.is_variant_c());
+		assert!(!e.is_variant_d());
+		assert!(!e.is_variant_e());
+		assert!(e.is_variant_f());
 	}
 
 	#[test]
@@ -183,8 +188,7 @@ mod fields{
 mod nofields{
 	use enum_traits::*;
 
-	#[cfg_attr(feature = "no_std_compile"      ,derive(Debug,Eq,PartialEq,EnumIndex,EnumFromIndex,EnumToIndex,EnumLen,EnumDiscriminant,                 EnumTag,EnumEnds,EnumIterator,EnumIter,EnumVariantName,EnumFromVariantName,EnumBitPattern))]
-	#[cfg_attr(not(feature = "no_std_compile") ,derive(Debug,Eq,PartialEq,EnumIndex,EnumFromIndex,EnumToIndex,EnumLen,EnumDiscriminant,EnumIsVariantFns,EnumTag,EnumEnds,EnumIterator,EnumIter,EnumVariantName,EnumFromVariantName,EnumBitPattern))]
+	#[derive(Debug,Eq,PartialEq,EnumIndex,EnumFromIndex,EnumToIndex,EnumLen,EnumDiscriminant,EnumIsVariantFns,EnumTag,EnumEnds,EnumIterator,EnumIter,EnumVariantName,EnumFromVariantName,EnumBitPattern)]
 	enum NoFields{
 		A,B,C,D,E,F
 	}
@@ -224,16 +228,26 @@ mod nofields{
 	#[test]
 	fn test_len(){
 		assert_eq!(6,<NoFields as Len>::len());
+		assert_eq!(6,NoFields::LEN);
+
+		let array: [u8; NoFields::LEN] = [0; NoFields::LEN];
+		assert_eq!(array.len(),6);
+	}
+
+	#[test]
+	fn test_variants(){
+		assert_eq!(NoFields::VARIANTS,&[NoFields::A,NoFields::B,NoFields::C,NoFields::D,NoFields::E,NoFields::F]);
+		assert!(NoFields::VARIANTS.iter().zip(NoFields::variants()).all(|(a,b)| *a == b));
 	}
 
 	#[test]
 	fn test_discriminants(){
-		assert_eq!(Some(NoFields::A),NoFields::from_discriminant(NoFields::A as usize));
-		assert_eq!(Some(NoFields::B),NoFields::from_discriminant(NoFields::B as usize));
-		assert_eq!(Some(NoFields::C),NoFields::from_discriminant(NoFields::C as usize));
-		assert_eq!(Some(NoFields::D),NoFields::from_discriminant(NoFields::D as usize));
-		assert_eq!(Some(NoFields::E),NoFields::from_discriminant(NoFields::E as usize));
-		assert_eq!(Some(NoFields::F),NoFields::from_discriminant(NoFields::F as usize));
+		assert_eq!(Some(NoFields::A),NoFields::from_discriminant(NoFields::A as u8));
+		assert_eq!(Some(NoFields::B),NoFields::from_discriminant(NoFields::B as u8));
+		assert_eq!(Some(NoFields::C),NoFields::from_discriminant(NoFields::C as u8));
+		assert_eq!(Some(NoFields::D),NoFields::from_discriminant(NoFields::D as u8));
+		assert_eq!(Some(NoFields::E),NoFields::from_discriminant(NoFields::E as u8));
+		assert_eq!(Some(NoFields::F),NoFields::from_discriminant(NoFields::F as u8));
 	}
 
 	#[test]
@@ -244,19 +258,853 @@ mod nofields{
 		assert_eq!(NoFields::D.variant_name(),"D");
 		assert_eq!(NoFields::E.variant_name(),"E");
 		assert_eq!(NoFields::F.variant_name(),"F");
+
+		assert_eq!(NoFields::NAMES,&["A","B","C","D","E","F"]);
 	}
 
 	#[test]
 	fn test_from_variant_name(){
 		use core::str::FromStr;
 
-		let mut v: Result<NoFields,()>;
+		let mut v: Result<NoFields,ParseVariantError>;
 		assert_eq!({v=NoFields::from_str("A"); v},Ok(NoFields::A));
 		assert_eq!({v=NoFields::from_str("B"); v},Ok(NoFields::B));
 		assert_eq!({v=NoFields::from_str("C"); v},Ok(NoFields::C));
 		assert_eq!({v=NoFields::from_str("D"); v},Ok(NoFields::D));
 		assert_eq!({v=NoFields::from_str("E"); v},Ok(NoFields::E));
 		assert_eq!({v=NoFields::from_str("F"); v},Ok(NoFields::F));
+
+		assert_eq!(NoFields::from_variant_name("A"),Ok(NoFields::A));
+		assert_eq!(NoFields::from_variant_name("F"),Ok(NoFields::F));
+	}
+
+	#[test]
+	fn test_from_variant_name_error(){
+		use core::str::FromStr;
+
+		let error = NoFields::from_variant_name("G").unwrap_err();
+		assert_eq!(error.input(),"G");
+		assert_eq!(error.type_name(),"NoFields");
+
+		let error = NoFields::from_str("a").unwrap_err();
+		assert_eq!(error.type_name(),"NoFields");
+		assert_eq!(error.input(),"a");
+		assert!(!error.is_input_truncated());
+	}
+
+	#[cfg(feature = "no_std")]
+	#[test]
+	fn test_from_variant_name_error_truncated(){
+		use core::str::FromStr;
+
+		//The owned input is truncated at a character boundary
+		let input = "0123456789012345678901234567890ä";
+		assert_eq!(input.len(),OWNED_INPUT_CAPACITY + 1);
+		let error = NoFields::from_str(input).unwrap_err();
+		assert_eq!(error.input(),&input[..OWNED_INPUT_CAPACITY - 1]);
+		assert!(error.is_input_truncated());
+
+		let error = NoFields::from_str(&input[..OWNED_INPUT_CAPACITY - 1]).unwrap_err();
+		assert_eq!(error.input(),&input[..OWNED_INPUT_CAPACITY - 1]);
+		assert!(!error.is_input_truncated());
+	}
+
+	#[cfg(feature = "no_std")]
+	#[test]
+	fn test_from_variant_name_error_eq(){
+		use core::str::FromStr;
+
+		//A borrowed error is equal to the owned one with the same input
+		let input = "0123456789012345678901234567890ä";
+		let borrowed = NoFields::from_variant_name(&input[..3]).unwrap_err();
+		let owned = NoFields::from_str(&input[..3]).unwrap_err();
+		assert_eq!(borrowed,owned);
+		assert_eq!(owned,borrowed.clone().into_owned());
+
+		//A truncated input is not equal to the whole input
+		let borrowed = NoFields::from_variant_name(input).unwrap_err();
+		let owned = NoFields::from_str(input).unwrap_err();
+		assert_ne!(borrowed,owned);
+		assert_ne!(NoFields::from_variant_name(&input[..OWNED_INPUT_CAPACITY - 1]).unwrap_err(),owned);
+	}
+
+	#[cfg(not(feature = "no_std"))]
+	#[test]
+	fn test_from_variant_name_error_display(){
+		use std::error::Error;
+
+		let error = "Z".parse::<NoFields>().unwrap_err();
+		assert_eq!(error.to_string(),"no variant named \"Z\" in `NoFields`");
+		let _: &dyn Error = &error;
+	}
+}
+
+mod from_variant_name{
+	use enum_traits::*;
+
+	#[derive(Debug,Eq,PartialEq,EnumFromVariantName)]
+	enum Animal{
+		#[enum_traits(alias = "Hound",alias = "Puppy")]
+		Dog,
+		#[enum_traits(alias = "Kitten")]
+		Cat,
+		Bird(u8),
+	}
+
+	#[derive(Debug,Eq,PartialEq,EnumFromVariantName)]
+	#[enum_traits(parse(case_insensitive))]
+	enum CaseInsensitive{
+		#[enum_traits(alias = "Hound")]
+		Dog,
+		Cat,
+		#[enum_traits(alias = "ÆSIR")]
+		Deity,
+	}
+
+	#[test]
+	fn test_aliases(){
+		assert_eq!(Animal::from_variant_name("Dog")   ,Ok(Animal::Dog));
+		assert_eq!(Animal::from_variant_name("Hound") ,Ok(Animal::Dog));
+		assert_eq!(Animal::from_variant_name("Puppy") ,Ok(Animal::Dog));
+		assert_eq!(Animal::from_variant_name("Cat")   ,Ok(Animal::Cat));
+		assert_eq!(Animal::from_variant_name("Kitten"),Ok(Animal::Cat));
+		assert!(Animal::from_variant_name("dog").is_err());
+		assert!(Animal::from_variant_name("hound").is_err());
+		assert!(Animal::from_variant_name("Bird").is_err());
+	}
+
+	#[test]
+	fn test_case_insensitive(){
+		assert_eq!(CaseInsensitive::from_variant_name("Dog")  ,Ok(CaseInsensitive::Dog));
+		assert_eq!(CaseInsensitive::from_variant_name("dog")  ,Ok(CaseInsensitive::Dog));
+		assert_eq!(CaseInsensitive::from_variant_name("DOG")  ,Ok(CaseInsensitive::Dog));
+		assert_eq!(CaseInsensitive::from_variant_name("hOUND"),Ok(CaseInsensitive::Dog));
+		assert_eq!(CaseInsensitive::from_variant_name("CAT")  ,Ok(CaseInsensitive::Cat));
+		assert_eq!(CaseInsensitive::from_variant_name("æsir") ,Ok(CaseInsensitive::Deity));
+		assert_eq!(CaseInsensitive::from_variant_name("Æsir") ,Ok(CaseInsensitive::Deity));
+		assert!(CaseInsensitive::from_variant_name("dogs").is_err());
+		assert!(CaseInsensitive::from_variant_name("").is_err());
+
+		let error = CaseInsensitive::from_variant_name("Wolf").unwrap_err();
+		assert_eq!(error.input(),"Wolf");
+	}
+}
+
+mod rename{
+	use enum_traits::*;
+
+	#[derive(Debug,Eq,PartialEq,EnumVariantName,EnumFromVariantName,EnumIsVariantFns)]
+	#[enum_traits(rename_all = "snake_case")]
+	enum Snake{
+		GuideDog,
+		HTTPServer,
+		Md5Hash,
+		#[enum_traits(rename = "Kitten",alias = "Cat")]
+		HouseCat,
+		Robot{speed: u8},
+	}
+
+	#[derive(Debug,Eq,PartialEq,EnumVariantName,EnumFromVariantName)]
+	#[enum_traits(rename_all = "SCREAMING-KEBAB-CASE")]
+	enum ScreamingKebab{
+		GuideDog,
+		Http2Server,
+	}
+
+	#[derive(Debug,Eq,PartialEq,EnumVariantName,EnumFromVariantName)]
+	#[enum_traits(rename_all = "camelCase",parse(case_insensitive))]
+	enum Camel{
+		GuideDog,
+		HTTPServer,
+	}
+
+	#[test]
+	fn test_variant_name(){
+		assert_eq!(Snake::GuideDog.variant_name()        ,"guide_dog");
+		assert_eq!(Snake::HTTPServer.variant_name()      ,"http_server");
+		assert_eq!(Snake::Md5Hash.variant_name()         ,"md5_hash");
+		assert_eq!(Snake::HouseCat.variant_name()        ,"Kitten");
+		assert_eq!(Snake::Robot{speed: 0}.variant_name() ,"robot");
+		assert_eq!(ScreamingKebab::GuideDog.variant_name()   ,"GUIDE-DOG");
+		assert_eq!(ScreamingKebab::Http2Server.variant_name(),"HTTP2-SERVER");
+		assert_eq!(Camel::GuideDog.variant_name()  ,"guideDog");
+		assert_eq!(Camel::HTTPServer.variant_name(),"httpServer");
+	}
+
+	#[test]
+	fn test_from_variant_name(){
+		assert_eq!(Snake::from_variant_name("guide_dog")  ,Ok(Snake::GuideDog));
+		assert_eq!(Snake::from_variant_name("http_server"),Ok(Snake::HTTPServer));
+		assert_eq!(Snake::from_variant_name("Kitten")     ,Ok(Snake::HouseCat));
+		assert_eq!(Snake::from_variant_name("Cat")        ,Ok(Snake::HouseCat));
+		assert!(Snake::from_variant_name("GuideDog").is_err());
+		assert!(Snake::from_variant_name("house_cat").is_err());
+		assert_eq!("md5_hash".parse(),Ok(Snake::Md5Hash));
+		assert_eq!(ScreamingKebab::from_variant_name("HTTP2-SERVER"),Ok(ScreamingKebab::Http2Server));
+		assert_eq!(Camel::from_variant_name("GUIDEDOG")  ,Ok(Camel::GuideDog));
+		assert_eq!(Camel::from_variant_name("httpserver"),Ok(Camel::HTTPServer));
+	}
+
+	#[test]
+	fn test_round_trip(){
+		for variant in [Snake::GuideDog,Snake::HTTPServer,Snake::Md5Hash,Snake::HouseCat].iter(){
+			assert_eq!(Snake::from_variant_name(variant.variant_name()).as_ref(),Ok(variant));
+		}
+	}
+
+	#[test]
+	fn test_is_variant_fns(){
+		assert!(Snake::GuideDog.is_guide_dog());
+		assert!(Snake::HTTPServer.is_http_server());
+		assert!(Snake::Md5Hash.is_md5_hash());
+		assert!(Snake::HouseCat.is_kitten());
+		assert!(Snake::Robot{speed: 0}.is_robot());
+		assert!(!Snake::GuideDog.is_kitten());
+	}
+}
+
+mod is_variant_fns{
+	#[allow(dead_code)]
+	#[derive(EnumIsVariantFns)]
+	enum State{
+		RobotArm,
+		HTTPServer(u8),
+		Md5Hash{hash: u8},
+		ABc,
+		AbC,
+		#[enum_traits(is_fn(name = "finished"))]
+		Done,
+		#[enum_traits(is_fn(prefix = "has_",name = "failed"))]
+		Error,
+	}
+
+	#[derive(EnumIsVariantFns)]
+	#[enum_traits(is_fn(prefix = "in_"),rename_all = "kebab-case")]
+	enum Mode{
+		ReadOnly,
+		#[enum_traits(is_fn(prefix = "is_"))]
+		WriteOnly,
+	}
+
+	#[test]
+	fn test_snake_case(){
+		assert!(State::RobotArm.is_robot_arm());
+		assert!(State::HTTPServer(0).is_http_server());
+		assert!(State::Md5Hash{hash: 0}.is_md5_hash());
+		assert!(State::ABc.is_a_bc());
+		assert!(State::AbC.is_ab_c());
+		assert!(!State::AbC.is_a_bc());
+	}
+
+	#[test]
+	fn test_is_fn(){
+		assert!(State::Done.is_finished());
+		assert!(State::Error.has_failed());
+		assert!(!State::Done.has_failed());
+		assert!(Mode::ReadOnly.in_read_only());
+		assert!(Mode::WriteOnly.is_write_only());
+		assert!(!Mode::ReadOnly.is_write_only());
+	}
+}
+
+mod constructors{
+	#[derive(Debug,PartialEq,EnumConstructors)]
+	enum Op{
+		Push(u32),
+		#[enum_traits(rename = "Remove")]
+		Pop(u32),
+		Move{from: u32,to: u32},
+		Type,
+		#[enum_traits(constructor(prefix = "make_",name = "nothing"))]
+		Nop,
+	}
+
+	#[derive(Debug,PartialEq,EnumConstructors)]
+	#[enum_traits(constructor(prefix = "new_",into))]
+	enum Value<T>{
+		Int(i64,T),
+		#[enum_traits(constructor(prefix = "",name = "index"))]
+		Index(i64,T),
+		Empty,
+	}
+
+	#[derive(EnumConstructors)]
+	#[allow(dead_code)]
+	enum Mixed{
+		A(u8),
+		B(u16),
+	}
+
+	#[test]
+	fn test_fns(){
+		assert_eq!(Op::push(1), Op::Push(1));
+		assert_eq!(Op::remove(2), Op::Pop(2));
+		assert_eq!(Op::r#move(1,2), Op::Move{from: 1,to: 2});
+		assert_eq!(Op::r#type(), Op::Type);
+		assert_eq!(Op::make_nothing(), Op::Nop);
+	}
+
+	#[test]
+	fn test_into(){
+		assert_eq!(Value::new_int(1i8,'a'), Value::Int(1,'a'));
+		assert_eq!(Value::index(2u32,'b'), Value::Index(2,'b'));
+		assert_eq!(Value::<()>::new_empty(), Value::Empty);
+	}
+
+	#[test]
+	fn test_table(){
+		assert_eq!(Op::CONSTRUCTORS.len(), 2);
+		assert_eq!(Op::CONSTRUCTORS[0].0, "Push");
+		assert_eq!(Op::CONSTRUCTORS[1].0, "Remove");
+		assert_eq!((Op::CONSTRUCTORS[1].1)(3), Op::Pop(3));
+
+		let (name,constructor) = Value::<&'static str>::CONSTRUCTORS[1];
+		assert_eq!(name, "Index");
+		assert_eq!(constructor(2,"x"), Value::Index(2,"x"));
+		assert_eq!(Value::<&'static str>::CONSTRUCTORS.len(), 2);
+	}
+}
+
+mod variant_structs{
+	use core::convert::TryFrom;
+
+	#[derive(Debug,PartialEq,EnumVariantStructs)]
+	#[enum_traits(variant_struct(derive(Debug,PartialEq)))]
+	pub enum Message<'t,T,U: Copy = u8> where T: Clone{
+		Quit,
+		/// Some text
+		Write(&'t str),
+		Move{x: i32,y: i32},
+		#[enum_traits(variant_struct(name = "Payload",vis = "pub(crate)",derive(Clone,Copy)))]
+		Data{len: usize,data: U},
+		Other(T),
+	}
+
+	type Msg = Message<'static,(),u8>;
+
+	#[test]
+	fn test_from(){
+		assert_eq!(Msg::from(MessageWrite("text")), Message::Write("text"));
+		assert_eq!(Msg::from(MessageMove{x: 1,y: 2}), Message::Move{x: 1,y: 2});
+		assert_eq!(Message::<(),u16>::from(Payload{len: 1,data: 2}), Message::Data{len: 1,data: 2});
+		assert_eq!(Message::<char>::from(MessageOther('a')), Message::Other('a'));
+	}
+
+	#[test]
+	fn test_try_from(){
+		assert_eq!(MessageMove::try_from(Msg::Move{x: 1,y: 2}), Ok(MessageMove{x: 1,y: 2}));
+		assert_eq!(MessageMove::try_from(Msg::Quit), Err(Message::Quit));
+		assert_eq!(MessageWrite::try_from(Msg::Write("text")).map(|write| write.0), Ok("text"));
+
+		let payload = Payload::try_from(Msg::Data{len: 1,data: 2}).unwrap();
+		let copy = payload;
+		assert_eq!((payload.len,copy.data), (1,2));
+		assert_eq!(MessageOther::try_from(Message::<char>::Other('a')), Ok(MessageOther('a')));
+	}
+}
+
+mod skip{
+	use enum_traits::*;
+
+	#[derive(Debug,Eq,PartialEq,EnumIndex,EnumFromIndex,EnumLen,EnumEnds,EnumIter,EnumVariantName,EnumFromVariantName)]
+	enum Skip{
+		#[enum_traits(skip)]
+		Invalid,
+		A,
+		#[enum_traits(skip(iter))]
+		Hidden,
+		B,
+		#[enum_traits(skip(parse))]
+		C,
+		#[enum_traits(skip)]
+		NonExhaustive(u8),
+	}
+
+	#[test]
+	fn test_len(){
+		assert_eq!(Skip::len(),3);
+		assert_eq!(<Skip as LenArray<u8>>::array_from_fn(|i| i as u8),[0,1,2]);
+	}
+
+	#[test]
+	fn test_ends(){
+		assert_eq!(Skip::first(),Skip::A);
+		assert_eq!(Skip::last() ,Skip::C);
+	}
+
+	#[test]
+	fn test_iter(){
+		let mut iter = Skip::variants();
+		assert_eq!(iter.len(),3);
+		assert_eq!(iter.next()     ,Some(Skip::A));
+		assert_eq!(iter.next_back(),Some(Skip::C));
+		assert_eq!(iter.next()     ,Some(Skip::B));
+		assert_eq!(iter.next()     ,None);
+	}
+
+	#[test]
+	fn test_index(){
+		assert_eq!(Skip::from_index(0),Some(Skip::A));
+		assert_eq!(Skip::from_index(1),Some(Skip::B));
+		assert_eq!(Skip::from_index(2),Some(Skip::C));
+		assert_eq!(Skip::from_index(3),None);
+		assert!(Skip::variants().eq((0..Skip::LEN as u8).filter_map(Skip::from_index)));
+	}
+
+	#[test]
+	fn test_from_variant_name(){
+		assert_eq!(Skip::from_variant_name("A")     ,Ok(Skip::A));
+		assert_eq!(Skip::from_variant_name("Hidden"),Ok(Skip::Hidden));
+		assert!(Skip::from_variant_name("Invalid").is_err());
+		assert!(Skip::from_variant_name("C").is_err());
+		assert_eq!(Skip::C.variant_name(),"C");
+	}
+}
+
+mod default_fields{
+	use enum_traits::*;
+
+	#[derive(Debug,Eq,PartialEq,EnumIndex,EnumFromIndex,EnumToIndex,EnumLen,EnumEnds,EnumIter,EnumFromVariantName)]
+	#[enum_traits(default_fields)]
+	enum Command{
+		Move{dx: i32,dy: i32},
+		Wait(u8),
+		#[enum_traits(construct = "Command::Jump(1,true)")]
+		Jump(u8,bool),
+		Quit,
+		#[enum_traits(construct = "Command::Move{dx: 1,dy: 0}",rename = "Right")]
+		MoveRight,
+	}
+
+	#[derive(Debug,Eq,PartialEq,EnumIndex,EnumFromIndex,EnumEnds,EnumIter,EnumFromVariantName)]
+	enum Construct{
+		#[enum_traits(construct = "Construct::Repeat(2)")]
+		Repeat(u8),
+		Stop,
+		#[enum_traits(skip(iter))]
+		Unconstructible(u8),
+	}
+
+	#[test]
+	fn test_from_index(){
+		assert_eq!(Command::from_index(0),Some(Command::Move{dx: 0,dy: 0}));
+		assert_eq!(Command::from_index(1),Some(Command::Wait(0)));
+		assert_eq!(Command::from_index(2),Some(Command::Jump(1,true)));
+		assert_eq!(Command::from_index(3),Some(Command::Quit));
+		assert_eq!(Command::from_index(4),Some(Command::Move{dx: 1,dy: 0}));
+		assert_eq!(Command::from_index(5),None);
+		assert_eq!(unsafe{Command::from_index_unchecked(1)},Command::Wait(0));
+	}
+
+	#[test]
+	fn test_ends(){
+		assert_eq!(Command::first(),Command::Move{dx: 0,dy: 0});
+		assert_eq!(Command::last() ,Command::Move{dx: 1,dy: 0});
+		assert_eq!(Construct::first(),Construct::Repeat(2));
+	}
+
+	#[test]
+	fn test_iter(){
+		let mut iter = Command::variants();
+		assert_eq!(iter.len(),Command::len());
+		assert_eq!(iter.next()     ,Some(Command::Move{dx: 0,dy: 0}));
+		assert_eq!(iter.next()     ,Some(Command::Wait(0)));
+		assert_eq!(iter.next_back(),Some(Command::Move{dx: 1,dy: 0}));
+		assert_eq!(iter.next_back(),Some(Command::Quit));
+		assert_eq!(iter.next()     ,Some(Command::Jump(1,true)));
+		assert_eq!(iter.next()     ,None);
+	}
+
+	#[test]
+	fn test_from_variant_name(){
+		assert_eq!(Command::from_variant_name("Move") ,Ok(Command::Move{dx: 0,dy: 0}));
+		assert_eq!(Command::from_variant_name("Wait") ,Ok(Command::Wait(0)));
+		assert_eq!(Command::from_variant_name("Jump") ,Ok(Command::Jump(1,true)));
+		assert_eq!(Command::from_variant_name("Right"),Ok(Command::Move{dx: 1,dy: 0}));
+		assert_eq!("Quit".parse::<Command>()          ,Ok(Command::Quit));
+
+		assert_eq!(Construct::from_variant_name("Repeat"),Ok(Construct::Repeat(2)));
+		assert!(Construct::from_variant_name("Unconstructible").is_err());
+	}
+}
+
+mod props{
+	use enum_traits::*;
+
+	#[derive(EnumVariantProps)]
+	enum Props{
+		#[enum_traits(prop(class = "a",label = "Variant A"))]
+		A,
+		#[enum_traits(prop(class = "b"),prop(r#type = "tuple"))]
+		#[enum_traits(prop(label = "Variant B"))]
+		B(u8),
+		#[enum_traits(prop(class = "c"))]
+		C{c: u16},
+		D,
+	}
+
+	#[derive(EnumVariantProps)]
+	enum Empty{}
+
+	#[test]
+	fn test_props(){
+		assert_eq!(Props::A.props()      ,&[("class","a"),("label","Variant A")]);
+		assert_eq!(Props::B(0).props()   ,&[("class","b"),("type","tuple"),("label","Variant B")]);
+		assert_eq!(Props::C{c: 0}.props(),&[("class","c")]);
+		assert_eq!(Props::D.props()      ,&[]);
+	}
+
+	#[test]
+	fn test_get_prop(){
+		assert_eq!(Props::A.get_prop("label")   ,Some("Variant A"));
+		assert_eq!(Props::B(0).get_prop("type") ,Some("tuple"));
+		assert_eq!(Props::C{c: 0}.get_prop("class"),Some("c"));
+		assert_eq!(Props::C{c: 0}.get_prop("label"),None);
+		assert_eq!(Props::D.get_prop("class")   ,None);
+	}
+}
+
+mod assoc{
+	use enum_traits::*;
+
+	const SERVER_ERROR: u16 = 500;
+
+	#[derive(Debug,PartialEq,EnumAssoc,EnumVariantName)]
+	#[enum_traits(assoc(unique code: u16,unique symbol: char,weight: f32,description: &'static str),assoc(unique r#type: i8))]
+	#[enum_traits(default_fields)]
+	enum Status{
+		#[enum_traits(code = 200,symbol = 'O',weight = 1.0,description = "ok",r#type = -1)]
+		Ok,
+		#[enum_traits(code = 0x194,symbol = 'N',weight = 0.5,description = "not found",r#type = 1 << 2)]
+		NotFound{path: u8},
+		#[enum_traits(code = SERVER_ERROR,symbol = 'E',weight = 0.5,description = "error",r#type = 0)]
+		#[enum_traits(construct = "Status::Error(1)")]
+		Error(u8),
+	}
+
+	#[test]
+	fn test_values(){
+		assert_eq!(Status::Ok.code()                ,200);
+		assert_eq!(Status::NotFound{path: 0}.code() ,404);
+		assert_eq!(Status::Error(0).code()          ,500);
+		assert_eq!(Status::NotFound{path: 0}.symbol(),'N');
+		assert_eq!(Status::Error(0).weight()        ,0.5);
+		assert_eq!(Status::Ok.description()         ,"ok");
+		assert_eq!(Status::Ok.r#type()              ,-1);
+	}
+
+	#[test]
+	fn test_from_unique(){
+		assert_eq!(Status::from_code(200),Some(Status::Ok));
+		assert_eq!(Status::from_code(404),Some(Status::NotFound{path: 0}));
+		assert_eq!(Status::from_code(500),Some(Status::Error(1)));
+		assert_eq!(Status::from_code(418),None);
+		assert_eq!(Status::from_symbol('E'),Some(Status::Error(1)));
+		assert_eq!(Status::from_symbol('X'),None);
+		assert_eq!(Status::from_type(4),Some(Status::NotFound{path: 0}));
+		assert_eq!(Status::Ok.variant_name(),"Ok");
+	}
+}
+
+mod display{
+	use core::fmt::{self,Write};
+
+	#[allow(dead_code)]
+	#[derive(EnumDisplay)]
+	#[enum_traits(rename_all = "snake_case")]
+	enum Display<T: fmt::Display>{
+		UnitVariant,
+		#[enum_traits(rename = "tuple")]
+		Tuple(u8,T),
+		#[enum_traits(display = "tuple {1} of {0:03}")]
+		Formatted(u8,T),
+		#[enum_traits(display = "{{{type}}}: {value:?} ({value:>width$})")]
+		Struct{r#type: char,value: &'static str,width: usize},
+		#[enum_traits(display = "unit")]
+		Unit,
+	}
+
+	/// A fixed size buffer to format into without allocating
+	struct Buffer{bytes: [u8; 64],len: usize}
+	impl Write for Buffer{
+		fn write_str(&mut self,s: &str) -> fmt::Result{
+			let bytes = self.bytes.get_mut(self.len..self.len + s.len()).ok_or(fmt::Error)?;
+			bytes.copy_from_slice(s.as_bytes());
+			self.len+= s.len();
+			Ok(())
+		}
+	}
+
+	fn format(args: fmt::Arguments,f: fn(&str)){
+		let mut buffer = Buffer{bytes: [0; 64],len: 0};
+		buffer.write_fmt(args).unwrap();
+		f(core::str::from_utf8(&buffer.bytes[..buffer.len]).unwrap());
+	}
+
+	#[test]
+	fn test_names(){
+		format(format_args!("{}",Display::UnitVariant::<bool>) ,|s| assert_eq!(s,"unit_variant"));
+		format(format_args!("{}",Display::Tuple(1,true))       ,|s| assert_eq!(s,"tuple"));
+		format(format_args!("[{:<6}]",Display::Tuple(1,true))  ,|s| assert_eq!(s,"[tuple ]"));
+	}
+
+	#[test]
+	fn test_templates(){
+		format(format_args!("{}",Display::Formatted(7,true)),|s| assert_eq!(s,"tuple true of 007"));
+		format(format_args!("{}",Display::Struct::<bool>{r#type: 'x',value: "abc",width: 5}),|s| assert_eq!(s,"{x}: \"abc\" (  abc)"));
+		format(format_args!("{:>10}",Display::Unit::<bool>),|s| assert_eq!(s,"unit"));
+	}
+}
+
+mod accessors{
+	#[derive(Debug,PartialEq,EnumAccessors,EnumVariantName)]
+	enum Message<T>{
+		Quit,
+		Move{x: i32,y: i32},
+		Write(&'static str),
+		Pair(u8,T),
+		#[enum_traits(rename = "Resized")]
+		Resize{size: (u16,u16)},
+	}
+
+	#[derive(Debug,PartialEq,EnumAccessors)]
+	enum Single{
+		Value(u8),
+	}
+
+	#[test]
+	fn test_as(){
+		let message: Message<bool> = Message::Move{x: 1,y: 2};
+		assert_eq!(message.as_move() ,Some((&1,&2)));
+		assert_eq!(message.as_write(),None);
+		assert_eq!(Message::Pair(1,true).as_pair(),Some((&1,&true)));
+		assert_eq!(Message::<bool>::Write("a").as_write(),Some(&"a"));
+		assert_eq!(Message::<bool>::Resize{size: (1,2)}.as_resized(),Some(&(1,2)));
+		assert_eq!(Single::Value(1).as_value(),Some(&1));
+	}
+
+	#[test]
+	fn test_as_mut(){
+		let mut message = Message::Pair(1,false);
+		if let Some((n,b)) = message.as_pair_mut(){
+			*n+= 1;
+			*b = true;
+		}
+		assert_eq!(message,Message::Pair(2,true));
+		assert_eq!(message.as_move_mut(),None);
+
+		let mut single = Single::Value(1);
+		*single.as_value_mut().unwrap() = 2;
+		assert_eq!(single,Single::Value(2));
+	}
+
+	#[test]
+	fn test_into(){
+		assert_eq!(Message::<bool>::Move{x: 1,y: 2}.into_move(),Ok((1,2)));
+		assert_eq!(Message::<bool>::Write("a").into_write(),Ok("a"));
+		assert_eq!(Message::<bool>::Quit.into_write(),Err(Message::Quit));
+		assert_eq!(Single::Value(3).into_value(),Ok(3));
+	}
+
+	#[test]
+	fn test_expect(){
+		assert_eq!(Message::<bool>::Move{x: 1,y: 2}.expect_move("a move"),(1,2));
+		assert_eq!(Message::Pair(1,'a').expect_pair("a pair"),(1,'a'));
+	}
+
+	#[test]
+	#[should_panic(expected = "a message: expected the variant `Resized`, found `Quit`")]
+	fn test_expect_panic(){
+		Message::<bool>::Quit.expect_resized("a message");
+	}
+}
+
+mod generics{
+	use enum_traits::*;
+
+	pub trait Trait{}
+	impl Trait for u8{}
+	impl Trait for u16{}
+
+	#[derive(Debug,Eq,PartialEq,EnumIndex,EnumFromIndex,EnumLen,EnumEnds,EnumIter,EnumTag,EnumVariantName)]
+	pub enum Generic<'a,T: Trait + 'a> where T: Clone{
+		A,
+		B,
+		#[enum_traits(skip)]
+		Borrowed(&'a T),
+		#[enum_traits(skip)]
+		Owned{value: T},
+	}
+
+	//Generic code going from the tags back to the parameterized enum
+	fn count<E: Tag>(values: &[E],tag: E::Enum) -> usize
+		where E::Enum: PartialEq
+	{
+		values.iter().filter(|value| value.tag() == tag).count()
+	}
+
+	#[test]
+	fn test_iter(){
+		let mut iter: GenericIter<'static,u8> = Generic::variants();
+		assert_eq!(iter.len(),2);
+		assert_eq!(iter.next()     ,Some(Generic::A));
+		assert_eq!(iter.next_back(),Some(Generic::B));
+		assert_eq!(iter.next()     ,None);
+
+		assert_eq!(Generic::<u16>::variants().count(),Generic::<u16>::len());
+		assert_eq!(Generic::<'static,u8>::VARIANTS,&[Generic::A,Generic::B]);
+		assert_eq!(Generic::<u8>::NAMES,&["A","B","Borrowed","Owned"]);
+	}
+
+	#[test]
+	fn test_index(){
+		assert_eq!(Generic::<u8>::first(),Generic::A);
+		assert_eq!(Generic::<u8>::last() ,Generic::B);
+		assert_eq!(Generic::<u8>::B.tag().index(),1);
+		assert_eq!(Generic::<u8>::from_index(0),Some(Generic::A));
+		assert_eq!(Generic::<u8>::from_index(2),None);
+	}
+
+	#[test]
+	fn test_tag(){
+		let value = 1u8;
+		let values = [Generic::A,Generic::Borrowed(&value),Generic::Owned{value: 2},Generic::Borrowed(&value)];
+		assert_eq!(values[1].tag(),GenericTag::Borrowed);
+		assert_eq!(values[2].tag(),GenericTag::Owned);
+		assert_eq!(count(&values,GenericTag::Borrowed),2);
+		assert_eq!(count(&values,GenericTag::B),0);
+		assert_eq!(values[2].variant_name(),"Owned");
+	}
+}
+
+mod generated_types{
+	use enum_traits::*;
+
+	//Types with the default names of the generated types
+	#[allow(dead_code)]struct ShapeTag;
+	#[allow(dead_code)]struct ShapeIter;
+
+	#[derive(Debug,PartialEq,EnumTag,EnumIter,EnumIndex,EnumFromIndex)]
+	#[enum_traits(default_fields)]
+	#[enum_traits(tag(name = "Kind",vis = "pub(crate)",derive(PartialOrd,Ord,EnumIter,enum_traits_macros::EnumVariantName),iter(name = "Kinds",vis = "pub(crate)",derive(Clone))))]
+	#[enum_traits(iter(name = "Shapes",vis = "pub(self)",derive(Clone,Debug)))]
+	enum Shape{
+		/// A point
+		Point,
+		/// A circle with a radius
+		Circle(u8),
+		#[cfg(not(any()))]
+		Square{side: u8},
+	}
+
+	#[test]
+	fn test_tag(){
+		assert_eq!(Shape::Circle(1).tag(),Kind::Circle);
+		assert!(Kind::Point < Kind::Circle);
+		assert_eq!(Kind::Circle.max(Kind::Square),Kind::Square);
+		assert!(Kind::variants().map(|kind| kind.variant_name()).eq(["Point","Circle","Square"].iter().cloned()));
+		assert_eq!(Shape::Square{side: 2}.tag(),Kind::Square);
+
+		let mut kinds: Kinds = Kind::variants();
+		assert_eq!(kinds.next(),Some(Kind::Point));
+		assert_eq!(kinds.clone().count(),2);
+	}
+
+	#[test]
+	fn test_tag_index(){
+		//Without skipped variants, the index of the tag is the index of the value
+		for shape in Shape::variants(){
+			assert_eq!(Shape::from_index(shape.tag().index()),Some(shape));
+		}
+
+		//After a skipped variant, the indices differ
+		assert_eq!(Message::from_index(Message::Ping.tag().index()),Some(Message::Ping));
+		assert_eq!(Message::from_index(Message::Text("").tag().index()),Some(Message::Binary{len: 0}));
+	}
+
+	#[allow(dead_code)]
+	#[derive(Debug,PartialEq,EnumTag,EnumIndex,EnumFromIndex)]
+	#[enum_traits(rename_all = "kebab-case",default_fields)]
+	enum Message{
+		Ping,
+		#[enum_traits(skip)]
+		Unknown(u8),
+		Text(&'static str),
+		#[enum_traits(rename = "bin")]
+		Binary{len: usize},
+	}
+
+	//Generic code counting the values by their tags
+	fn counts<E>(values: &[E]) -> [usize; 8]
+		where E: Tag,E::Enum: ToIndex<Type = u8> + Len
+	{
+		let mut counts = [0; 8];
+		for value in values{
+			counts[value.tag().into_index() as usize]+= 1;
+		}
+		counts
+	}
+
+	#[test]
+	fn test_tag_query(){
+		let messages = [Message::Ping,Message::Text("a"),Message::Binary{len: 1},Message::Text("b"),Message::Unknown(0)];
+		assert!(messages[0] == MessageTag::Ping);
+		assert!(messages[1] != MessageTag::Ping);
+		assert!(messages[2].is(MessageTag::Binary));
+		assert!(!messages[2].is(MessageTag::Text));
+		assert_eq!(messages.iter().filter(|message| message.is(MessageTag::Text)).count(),2);
+
+		assert_eq!(messages[0].tag_name(),"ping");
+		assert_eq!(messages[2].tag_name(),"bin");
+		assert_eq!(messages[4].tag_name(),"unknown");
+	}
+
+	#[test]
+	fn test_tag_traits(){
+		//Skipped variants have tags, making every message countable
+		assert_eq!(MessageTag::LEN,4);
+		assert_eq!(MessageTag::Ping.index()   ,0);
+		assert_eq!(MessageTag::Unknown.index(),1);
+		assert_eq!(MessageTag::Text.index()   ,2);
+		assert_eq!(MessageTag::Binary.index() ,3);
+		assert_eq!(MessageTag::from_index(3),Some(MessageTag::Binary));
+		assert_eq!(MessageTag::from_index(4),None);
+		let variants: MessageTagIter = MessageTag::variants();
+		assert!(variants.eq([MessageTag::Ping,MessageTag::Unknown,MessageTag::Text,MessageTag::Binary].iter().cloned()));
+		assert_eq!(MessageTag::Binary.variant_name(),"bin");
+
+		let messages = [Message::Ping,Message::Text("a"),Message::Binary{len: 1},Message::Text("b"),Message::Unknown(0)];
+		assert_eq!(&counts(&messages)[..MessageTag::LEN],&[1,1,2,1]);
+		assert_eq!(Kind::Circle.index(),1);
+	}
+
+	#[allow(dead_code)]
+	#[derive(EnumTag)]
+	#[repr(u16)]
+	enum Wide{
+		A(u8),
+		B,
+		C{c: u32},
+	}
+
+	#[test]
+	fn test_tag_repr(){
+		assert_eq!(::core::mem::size_of::<WideTag>(),2);
+		assert_eq!(Wide::C{c: 0}.tag().index(),2u16);
+		assert_eq!(WideTag::from_index(1),Some(WideTag::B));
+		assert_eq!(unsafe{WideTag::from_index_unchecked(2)},WideTag::C);
+		assert_eq!(WideTag::from_index(3),None);
+	}
+
+	#[test]
+	fn test_iter(){
+		let mut iter: Shapes = Shape::variants();
+		assert_eq!(iter.next(),Some(Shape::Point));
+		let copy = iter.clone();
+		assert_eq!(iter.next(),Some(Shape::Circle(0)));
+		assert_eq!(iter.next(),Some(Shape::Square{side: 0}));
+		assert_eq!(iter.next(),None);
+		assert_eq!(copy.len(),2);
 	}
 }
 
@@ -294,6 +1142,124 @@ mod discriminants{
 		assert_eq!(Some(SomeDiscriminants::F),SomeDiscriminants::from_discriminant(SomeDiscriminants::F as u32));
 	}
 
+	#[test]
+	fn test_discriminant(){
+		assert_eq!(1 ,Discriminants::A.discriminant());
+		assert_eq!(33,Discriminants::F.discriminant());
+		for variant in SomeDiscriminants::variants(){
+			assert_eq!(SomeDiscriminants::from_discriminant(variant.discriminant()),Some(variant));
+		}
+		assert_eq!(2 ,SomeDiscriminants::B.discriminant());
+		assert_eq!(17,SomeDiscriminants::F.discriminant());
+	}
+
+	const BASE: isize = 100;
+
+	#[derive(Debug,Eq,PartialEq,EnumDiscriminant)]
+	enum Signed{
+		A = -130,
+		B,
+		C = 1 << 4,
+		D = (3 - 10) * 2,
+	}
+
+	#[derive(Debug,Eq,PartialEq,EnumDiscriminant)]
+	enum Unsigned{
+		A = 0x10 | 0x01,
+		B = 300,
+	}
+
+	#[derive(Debug,Eq,PartialEq,EnumDiscriminant)]
+	enum Constant{
+		A = BASE,
+		B,
+		C = -BASE,
+	}
+
+	#[derive(Debug,Eq,PartialEq,EnumDiscriminant)]
+	#[repr(i8)]
+	enum ConstantFields{
+		A(u8) = -1,
+		B = BASE as i8,
+		C,
+		D{d: u8},
+	}
+
+	#[test]
+	fn test_discriminant_type(){
+		let _: i16   = Signed::A.discriminant();
+		let _: u16   = Unsigned::A.discriminant();
+		let _: isize = Constant::A.discriminant();
+		let _: i8    = ConstantFields::B.discriminant();
+		let _: u8    = Discriminants::A.discriminant();
+	}
+
+	#[test]
+	fn test_discriminant_signed(){
+		assert_eq!(-130,Signed::A.discriminant());
+		assert_eq!(-129,Signed::B.discriminant());
+		assert_eq!(16  ,Signed::C.discriminant());
+		assert_eq!(-14 ,Signed::D.discriminant());
+		assert_eq!(Some(Signed::A),Signed::from_discriminant(-130));
+		assert_eq!(Some(Signed::B),Signed::from_discriminant(-129));
+		assert_eq!(Some(Signed::C),Signed::from_discriminant(16));
+		assert_eq!(Some(Signed::D),Signed::from_discriminant(-14));
+		assert_eq!(None           ,Signed::from_discriminant(0));
+
+		assert_eq!(Some(Unsigned::A),Unsigned::from_discriminant(17));
+		assert_eq!(Some(Unsigned::B),Unsigned::from_discriminant(300));
+	}
+
+	#[test]
+	fn test_discriminant_constant(){
+		assert_eq!(100 ,Constant::A.discriminant());
+		assert_eq!(101 ,Constant::B.discriminant());
+		assert_eq!(-100,Constant::C.discriminant());
+		assert_eq!(Some(Constant::A),Constant::from_discriminant(100));
+		assert_eq!(Some(Constant::B),Constant::from_discriminant(101));
+		assert_eq!(Some(Constant::C),Constant::from_discriminant(-100));
+		assert_eq!(None             ,Constant::from_discriminant(0));
+
+		assert_eq!(-1 ,ConstantFields::A(0).discriminant());
+		assert_eq!(100,ConstantFields::B.discriminant());
+		assert_eq!(101,ConstantFields::C.discriminant());
+		assert_eq!(102,ConstantFields::D{d: 0}.discriminant());
+		assert_eq!(None                   ,ConstantFields::from_discriminant(-1));
+		assert_eq!(Some(ConstantFields::B),ConstantFields::from_discriminant(100));
+		assert_eq!(Some(ConstantFields::C),ConstantFields::from_discriminant(101));
+		assert_eq!(None                   ,ConstantFields::from_discriminant(102));
+	}
+
+	#[derive(Debug,Eq,PartialEq,EnumDiscriminant)]
+	#[repr(C,u16)]
+	enum Message{
+		Ping,
+		Data(u32) = 0x100,
+		Text{len: u16},
+		Pong,
+		Close = 0xFFFF,
+	}
+
+	#[test]
+	fn test_discriminant_fields(){
+		assert_eq!(0x000 ,Message::Ping.discriminant());
+		assert_eq!(0x100 ,Message::Data(7).discriminant());
+		assert_eq!(0x101 ,Message::Text{len: 3}.discriminant());
+		assert_eq!(0x102 ,Message::Pong.discriminant());
+		assert_eq!(0xFFFF,Message::Close.discriminant());
+
+		assert_eq!(Some(Message::Ping) ,Message::from_discriminant(0x000));
+		assert_eq!(None                ,Message::from_discriminant(0x100));
+		assert_eq!(None                ,Message::from_discriminant(0x101));
+		assert_eq!(Some(Message::Pong) ,Message::from_discriminant(0x102));
+		assert_eq!(Some(Message::Close),Message::from_discriminant(0xFFFF));
+		assert_eq!(None                ,Message::from_discriminant(1));
+
+		//The discriminant is the leading tag of the representation
+		let message = Message::Text{len: 3};
+		assert_eq!(unsafe{*(&message as *const Message as *const u16)},message.discriminant());
+	}
+
 	#[allow(dead_code)]
 	#[test]
 	fn test_len(){
@@ -302,6 +1268,132 @@ mod discriminants{
 	}
 }
 
+mod repr{
+	use core::convert::TryFrom;
+	use enum_traits::*;
+
+	const BASE: i16 = -200;
+
+	#[derive(Debug,Eq,PartialEq,EnumRepr)]
+	#[repr(u8)]
+	enum Opcode{
+		Nop,
+		Load = 0x10,
+		Store,
+		Halt = 0xFF,
+	}
+
+	#[derive(Debug,Eq,PartialEq,EnumRepr)]
+	#[repr(i16)]
+	enum Signed{
+		A = BASE,
+		B,
+		C = 1 << 8,
+	}
+
+	fn parse(bytes: &[u8]) -> Result<(Opcode,Opcode),TryFromReprError<u8>>{
+		Ok((Opcode::try_from(bytes[0])?,Opcode::try_from(bytes[1])?))
+	}
+
+	#[test]
+	fn test_try_from(){
+		assert_eq!(Opcode::try_from(0x00),Ok(Opcode::Nop));
+		assert_eq!(Opcode::try_from(0x11),Ok(Opcode::Store));
+		assert_eq!(Opcode::try_from(0xFF),Ok(Opcode::Halt));
+		assert_eq!(Signed::try_from(-200),Ok(Signed::A));
+		assert_eq!(Signed::try_from(-199),Ok(Signed::B));
+		assert_eq!(Signed::try_from(256) ,Ok(Signed::C));
+
+		let error = Opcode::try_from(0x12).unwrap_err();
+		assert_eq!(error,TryFromReprError::new(0x12,"Opcode"));
+		assert_eq!(error.value(),0x12);
+		assert_eq!(error.type_name(),"Opcode");
+		assert_eq!(Signed::try_from(0).unwrap_err().into_value(),0);
+
+		assert_eq!(parse(&[0x10,0xFF]),Ok((Opcode::Load,Opcode::Halt)));
+		assert_eq!(parse(&[0x10,0x01]),Err(TryFromReprError::new(0x01,"Opcode")));
+	}
+
+	#[test]
+	fn test_into(){
+		assert_eq!(u8::from(Opcode::Nop),0x00);
+		assert_eq!(u8::from(Opcode::Store),0x11);
+		let n: i16 = Signed::B.into();
+		assert_eq!(n,-199);
+	}
+
+	#[test]
+	fn test_eq(){
+		assert!(Opcode::Load == 0x10);
+		assert!(Opcode::Load != 0x11);
+		assert!(Signed::B == -199);
+	}
+
+	#[cfg(not(feature = "no_std"))]
+	#[test]
+	fn test_error_display(){
+		assert_eq!(Opcode::try_from(7).unwrap_err().to_string(),"no variant with the discriminant 7 in `Opcode`");
+	}
+}
+
+mod unchecked{
+	use enum_traits::*;
+
+	#[derive(Debug,Eq,PartialEq,EnumIndex,EnumFromIndex,EnumToIndex,EnumLen,EnumIter,EnumDiscriminant)]
+	#[repr(u8)]
+	enum Contiguous{A,B,C,D}
+
+	#[derive(Debug,Eq,PartialEq,EnumIndex,EnumFromIndex,EnumToIndex,EnumLen,EnumIter,EnumDiscriminant)]
+	enum Sparse{A = 3,B = 7,C = -2}
+
+	#[derive(Debug,Eq,PartialEq,EnumIndex,EnumFromIndex,EnumLen,EnumIter,EnumDiscriminant)]
+	#[repr(u8)]
+	enum Skipped{
+		A,
+		#[enum_traits(skip)]
+		Invalid(u8),
+		B,
+	}
+
+	#[test]
+	fn test_from_index_unchecked(){
+		for variant in Contiguous::variants(){
+			assert_eq!(unsafe{Contiguous::from_index_unchecked(variant.index())},variant);
+		}
+		for variant in Sparse::variants(){
+			assert_eq!(unsafe{Sparse::from_index_unchecked(variant.index())},variant);
+		}
+		for (i,variant) in Skipped::variants().enumerate(){
+			assert_eq!(unsafe{Skipped::from_index_unchecked(i as u8)},variant);
+		}
+	}
+
+	#[test]
+	fn test_from_discriminant_unchecked(){
+		for variant in Contiguous::variants(){
+			assert_eq!(unsafe{Contiguous::from_discriminant_unchecked(variant.discriminant())},variant);
+		}
+		for variant in Sparse::variants(){
+			assert_eq!(unsafe{Sparse::from_discriminant_unchecked(variant.discriminant())},variant);
+		}
+		assert_eq!(unsafe{Skipped::from_discriminant_unchecked(2)},Skipped::B);
+	}
+
+	#[cfg(debug_assertions)]
+	#[test]
+	#[should_panic(expected = "called with an index out of range")]
+	fn test_from_index_unchecked_out_of_range(){
+		unsafe{Contiguous::from_index_unchecked(4)};
+	}
+
+	#[cfg(debug_assertions)]
+	#[test]
+	#[should_panic(expected = "called with a discriminant not belonging to a variant")]
+	fn test_from_discriminant_unchecked_out_of_range(){
+		unsafe{Sparse::from_discriminant_unchecked(0)};
+	}
+}
+
 mod large{
 	use enum_traits::*;
 
@@ -678,6 +1770,230 @@ mod large{
 	}
 }
 
+mod set{
+	use enum_traits::*;
+
+	#[derive(Debug,Clone,Copy,Eq,PartialEq,EnumIndex,EnumFromIndex,EnumToIndex,EnumLen,EnumBitPattern,EnumVariantName)]
+	enum Permission{
+		Read,Write,Execute,Delete,Admin,Guest,Owner,Group,Other
+	}
+
+	#[test]
+	fn test_insert_remove(){
+		let mut set = EnumSet::new();
+		assert!(set.is_empty());
+		assert!(set.insert(Permission::Read));
+		assert!(set.insert(Permission::Other));
+		assert!(!set.insert(Permission::Read));
+		assert_eq!(set.len(),2);
+
+		assert!(set.contains(&Permission::Read));
+		assert!(set.contains(&Permission::Other));
+		assert!(!set.contains(&Permission::Write));
+
+		assert!(set.remove(&Permission::Read));
+		assert!(!set.remove(&Permission::Read));
+		assert!(!set.contains(&Permission::Read));
+		assert_eq!(set.len(),1);
+
+		set.clear();
+		assert!(set.is_empty());
+	}
+
+	#[test]
+	fn test_operations(){
+		use core::iter::FromIterator;
+		fn set(variants: &[Permission]) -> EnumSet<Permission>{
+			EnumSet::from_iter(variants.iter().cloned())
+		}
+
+		let a = set(&[Permission::Read,Permission::Write,Permission::Owner]);
+		let b = set(&[Permission::Write,Permission::Other]);
+
+		assert_eq!(a.union(&b)               ,set(&[Permission::Read,Permission::Write,Permission::Owner,Permission::Other]));
+		assert_eq!(a.intersection(&b)        ,set(&[Permission::Write]));
+		assert_eq!(a.difference(&b)          ,set(&[Permission::Read,Permission::Owner]));
+		assert_eq!(a.symmetric_difference(&b),set(&[Permission::Read,Permission::Owner,Permission::Other]));
+		assert_eq!(a.complement()            ,set(&[Permission::Execute,Permission::Delete,Permission::Admin,Permission::Guest,Permission::Group,Permission::Other]));
+
+		assert_eq!(EnumSet::<Permission>::all().len(),9);
+		assert_eq!(EnumSet::<Permission>::all().complement(),EnumSet::new());
+		assert!(a.intersection(&b).is_subset(&a));
+		assert!(a.is_superset(&a.intersection(&b)));
+		assert!(a.difference(&b).is_disjoint(&b));
+	}
+
+	#[test]
+	fn test_iter(){
+		let mut set = EnumSet::new();
+		set.insert(Permission::Other);
+		set.insert(Permission::Write);
+		set.insert(Permission::Execute);
+
+		let mut iter = set.iter();
+		assert_eq!(Some(Permission::Write)  ,iter.next());
+		assert_eq!(Some(Permission::Execute),iter.next());
+		assert_eq!(Some(Permission::Other)  ,iter.next());
+		assert_eq!(None                     ,iter.next());
+	}
+
+	#[test]
+	fn test_bit_pattern(){
+		let mut set = EnumSet::new();
+		set.insert(Permission::Other);
+		assert_eq!(*set.bit_pattern(),Permission::Other.bit_pattern());
+
+		let set = EnumSet::<Permission>::from_bit_pattern([0b11111111,0b11111111]);
+		assert_eq!(set,EnumSet::all());
+	}
+
+	#[cfg(not(feature = "no_std"))]
+	#[test]
+	fn test_debug(){
+		let set: EnumSet<Permission> = [Permission::Execute,Permission::Read].iter().cloned().collect();
+		assert_eq!(format!("{:?}",set),"{Read, Execute}");
+	}
+}
+
+mod map{
+	use enum_traits::*;
+
+	#[derive(Debug,Clone,Copy,Eq,PartialEq,EnumIndex,EnumFromIndex,EnumToIndex,EnumLen,EnumVariantName)]
+	enum Color{
+		Red,Green,Blue
+	}
+
+	#[test]
+	fn test_from_fn(){
+		let mut map = EnumMap::from_fn(|color| match color{
+			Color::Red   => 0xFF0000,
+			Color::Green => 0x00FF00,
+			Color::Blue  => 0x0000FF,
+		});
+		assert_eq!(map.len(),3);
+		assert_eq!(map[Color::Red]  ,0xFF0000);
+		assert_eq!(map[Color::Green],0x00FF00);
+		assert_eq!(map[Color::Blue] ,0x0000FF);
+
+		map[Color::Green] = 0x008000;
+		assert_eq!(*map.get(&Color::Green),0x008000);
+		assert_eq!(map.replace(&Color::Blue,0x000080),0x0000FF);
+		assert_eq!(map.values(),&[0xFF0000,0x008000,0x000080]);
+	}
+
+	#[test]
+	fn test_iter(){
+		let mut map: EnumMap<Color,u32> = EnumMap::default();
+		for (color,value) in map.iter_mut(){
+			*value = color.index() as u32 * 10;
+		}
+
+		let mut iter = map.iter();
+		assert_eq!(iter.len(),3);
+		assert_eq!(Some((Color::Red  ,&00)),iter.next());
+		assert_eq!(Some((Color::Blue ,&20)),iter.next_back());
+		assert_eq!(Some((Color::Green,&10)),iter.next());
+		assert_eq!(None                    ,iter.next());
+	}
+
+	#[test]
+	fn test_partial(){
+		let mut map: EnumMap<Color,Option<&'static str>> = EnumMap::empty();
+		assert_eq!(map.insert(&Color::Red,"red"),None);
+		assert_eq!(map.insert(&Color::Blue,"blue"),None);
+		assert_eq!(map.insert(&Color::Blue,"navy"),Some("blue"));
+		assert!(map.contains_key(&Color::Red));
+		assert!(!map.contains_key(&Color::Green));
+
+		let mut present = map.present();
+		assert_eq!(Some((Color::Red ,&"red")) ,present.next());
+		assert_eq!(Some((Color::Blue,&"navy")),present.next());
+		assert_eq!(None                       ,present.next());
+
+		let mut map = map.into_total().unwrap_err();
+		map.insert(&Color::Green,"green");
+		let map = map.into_total().unwrap();
+		assert_eq!(map[Color::Green],"green");
+
+		let mut map = EnumMap::<Color,Option<u8>>::from_fn(|color| Some(color.index()));
+		assert_eq!(map.remove(&Color::Green),Some(1));
+		assert_eq!(map.remove(&Color::Green),None);
+	}
+
+	#[cfg(not(feature = "no_std"))]
+	#[test]
+	fn test_debug(){
+		let map = EnumMap::from_fn(|color: Color| color.index());
+		assert_eq!(format!("{:?}",map),"{Red: 0, Green: 1, Blue: 2}");
+	}
+}
+
+mod sequential{
+	use enum_traits::*;
+
+	#[derive(Debug,Eq,PartialEq,EnumSequential,EnumIndex,EnumToIndex,EnumFromIndex,EnumIter)]
+	enum Tab{
+		General,Network,Display,Advanced
+	}
+
+	#[test]
+	fn test_checked(){
+		assert_eq!(Some(Tab::Network) ,Tab::General.succ());
+		assert_eq!(Some(Tab::Advanced),Tab::Display.succ());
+		assert_eq!(None               ,Tab::Advanced.succ());
+		assert_eq!(None               ,Tab::General.pred());
+		assert_eq!(Some(Tab::Display) ,Tab::Advanced.pred());
+
+		assert_eq!(Some(Tab::General) ,Tab::General.offset(0));
+		assert_eq!(Some(Tab::Advanced),Tab::General.offset(3));
+		assert_eq!(None               ,Tab::General.offset(4));
+		assert_eq!(Some(Tab::General) ,Tab::Advanced.offset(-3));
+		assert_eq!(None               ,Tab::Advanced.offset(-4));
+		assert_eq!(None               ,Tab::Advanced.offset(isize::max_value()));
+		assert_eq!(None               ,Tab::Advanced.offset(isize::min_value()));
+	}
+
+	#[test]
+	fn test_wrapping(){
+		assert_eq!(Tab::Network ,Tab::General.succ_wrapping());
+		assert_eq!(Tab::General ,Tab::Advanced.succ_wrapping());
+		assert_eq!(Tab::Advanced,Tab::General.pred_wrapping());
+		assert_eq!(Tab::Display ,Tab::Advanced.pred_wrapping());
+
+		assert_eq!(Tab::Network ,Tab::General.offset_wrapping(5));
+		assert_eq!(Tab::Display ,Tab::General.offset_wrapping(-6));
+		assert_eq!(Tab::Advanced,Tab::Advanced.offset_wrapping(isize::min_value()));
+	}
+
+	#[test]
+	fn test_saturating(){
+		assert_eq!(Tab::Network ,Tab::General.succ_saturating());
+		assert_eq!(Tab::Advanced,Tab::Advanced.succ_saturating());
+		assert_eq!(Tab::General ,Tab::General.pred_saturating());
+		assert_eq!(Tab::Display ,Tab::Advanced.pred_saturating());
+
+		assert_eq!(Tab::Advanced,Tab::General.offset_saturating(10));
+		assert_eq!(Tab::General ,Tab::Display.offset_saturating(-10));
+		assert_eq!(Tab::Advanced,Tab::Display.offset_saturating(isize::max_value()));
+		assert_eq!(Tab::General ,Tab::Display.offset_saturating(isize::min_value()));
+	}
+
+	#[test]
+	fn test_distance(){
+		assert_eq!(0 ,Tab::distance(&Tab::Network,&Tab::Network));
+		assert_eq!(3 ,Tab::distance(&Tab::General,&Tab::Advanced));
+		assert_eq!(-2,Tab::distance(&Tab::Display,&Tab::General));
+	}
+
+	#[test]
+	fn test_index_order(){
+		for tab in Tab::variants(){
+			assert_eq!(Tab::distance(&Tab::General,&tab),tab.index() as isize);
+			assert_eq!(tab.succ(),Tab::from_index(tab.index() + 1));
+		}
+	}
+}
+
 mod readmemd{
 	use enum_traits::*;
 
@@ -871,7 +2187,8 @@ fn test_iter(){
 		assert_eq!(Some(T::A),t.next()); assert_eq!(t.len(),0);
 		assert_eq!(None,t.next());       assert_eq!(t.len(),0);
 
-		assert_eq!(t.last(),Some(T::A));
+		assert_eq!(t.last(),None);
+		assert_eq!(T::variants().last(),Some(T::A));
 	}{
 		#[derive(Debug,Eq,PartialEq,EnumEnds,EnumIter)]enum T{A,B,C}
 		let mut t = T::variants();
@@ -937,3 +2254,50 @@ fn test_iter(){
 		assert_eq!(None      ,t.next());
 	}
 }
+
+#[allow(dead_code)]
+#[test]
+fn test_iter_rev(){
+	use core::iter::{DoubleEndedIterator,Iterator};
+	use enum_traits::Iterable;
+
+	{
+		#[derive(Debug,Eq,PartialEq,EnumIter)]enum T{}
+		let mut t = T::variants();
+		assert_eq!(t.len(),0);
+		assert_eq!(None,t.next_back());
+		assert_eq!(None,t.next());
+	}{
+		#[derive(Debug,Eq,PartialEq,EnumIter)]enum T{A}
+		let mut t = T::variants();
+		assert_eq!(Some(T::A),t.next_back()); assert_eq!(t.len(),0);
+		assert_eq!(None,t.next());            assert_eq!(t.len(),0);
+		assert_eq!(None,t.next_back());       assert_eq!(t.len(),0);
+	}{
+		#[derive(Debug,Eq,PartialEq,EnumIter)]enum T{A,B,C,D,E}
+		let mut t = T::variants();
+		assert_eq!(t.len(),5);
+
+		assert_eq!(Some(T::E),t.next_back()); assert_eq!(t.len(),4);
+		assert_eq!(Some(T::A),t.next());      assert_eq!(t.len(),3);
+		assert_eq!(Some(T::D),t.next_back()); assert_eq!(t.len(),2);
+		assert_eq!(Some(T::B),t.next());      assert_eq!(t.len(),1);
+		assert_eq!(Some(T::C),t.next_back()); assert_eq!(t.len(),0);
+		assert_eq!(None      ,t.next());      assert_eq!(t.len(),0);
+		assert_eq!(None      ,t.next_back()); assert_eq!(t.len(),0);
+	}{
+		#[derive(Debug,Eq,PartialEq,EnumIter)]enum T{A,B,C,D}
+		let mut t = T::variants().rev();
+		assert_eq!(Some(T::D),t.next());
+		assert_eq!(Some(T::C),t.next());
+		assert_eq!(Some(T::B),t.next());
+		assert_eq!(Some(T::A),t.next());
+		assert_eq!(None      ,t.next());
+
+		let mut t = T::variants();
+		t.next();
+		assert_eq!(t.last(),Some(T::D));
+		assert_eq!(T::variants().rev().last(),Some(T::A));
+		assert_eq!(T::variants().count(),4);
+	}
+}